@@ -52,7 +52,7 @@ fn app() -> Html {
                     <li>{"padding: The padding inside the button."}</li>
                     <li>{"margin: The margin outside the button."}</li>
                     <li>{"background_color: The background color of the button. Default is BackgroundColor::Primary."}</li>
-                    <li>{"font_color: The font color of the button text. Default is TextColor::TextPrimary."}</li>
+                    <li>{"font_color: The font color of the button text. Default is the text color shown on the background color, e.g. TextColor::OnSecondary on BackgroundColor::Secondary."}</li>
                     <li>{"font_size: The font size of the button text."}</li>
                     <li>{"classes: Additional CSS classes to apply to the button."}</li>
                     <li>{"onclick: Callback to be executed when the button is clicked."}</li>
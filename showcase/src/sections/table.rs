@@ -1,16 +1,34 @@
 use yew::{function_component, html, Html};
 use zirv_ui::{
     options::flex::{FlexAlign, FlexDirection, FlexGap},
-    Container, Headline, Paragraph, CodeBlock, Subheadline, Table,
+    Column, Container, Headline, Paragraph, CodeBlock, Subheadline, Table,
     options::size::Width,
+    table::sort::SortKind,
 };
 
+#[derive(Clone, PartialEq)]
+struct User {
+    name: String,
+    age: u32,
+    active: bool,
+}
+
 #[function_component(TableSection)]
 pub fn table_section() -> Html {
-    let headers = vec!["Header 1".to_string(), "Header 2".to_string(), "Header 3".to_string()];
+    let columns = vec![
+        Column::new("Name", |user: &User| user.name.clone()),
+        Column::new("Age", |user: &User| user.age.to_string()).with_sort_kind(SortKind::Numeric),
+        Column::new("Status", |user: &User| if user.active { "Active" } else { "Inactive" }.to_string())
+            .with_renderer(|user: &User| html! {
+                <span class={if user.active { "success" } else { "error" }}>
+                    {if user.active { "Active" } else { "Inactive" }}
+                </span>
+            }),
+    ];
     let data = vec![
-        vec!["Row 1, Cell 1".to_string(), "Row 1, Cell 2".to_string(), "Row 1, Cell 3".to_string()],
-        vec!["Row 2, Cell 1".to_string(), "Row 2, Cell 2".to_string(), "Row 2, Cell 3".to_string()],
+        User { name: "Alice".to_string(), age: 31, active: true },
+        User { name: "Bob".to_string(), age: 9, active: false },
+        User { name: "Carol".to_string(), age: 47, active: true },
     ];
 
     html! {
@@ -18,25 +36,37 @@ pub fn table_section() -> Html {
             <Container flex_direction={FlexDirection::Column} flex_align={FlexAlign::Start} flex_gap={FlexGap::Large}>
                 <Headline>{"Table Component"}</Headline>
 
-                <Paragraph>{"The Table component is a highly customizable table for use in Yew applications using Zirv UI. It supports various layout and styling options for the table, rows, and cells."}</Paragraph>
+                <Paragraph>{"The Table component is a highly customizable table for use in Yew applications using Zirv UI. It is generic over the type of its rows: each column is described by a Column, which extracts the displayed value from a row and can render it as any Html, such as buttons, badges or links. It supports various layout and styling options for the table, rows, and cells."}</Paragraph>
 
                 <Subheadline>{"Basic Usage"}</Subheadline>
                 <Paragraph>{"Here's a simple example of how to use the Table component:"}</Paragraph>
                 <CodeBlock 
                     snippet={r#"
 use yew::{function_component, html, Html};
-use zirv_ui::{Table, options::size::Width};
+use zirv_ui::{Column, Table, options::size::Width};
+
+#[derive(Clone, PartialEq)]
+struct User {
+    name: String,
+    age: u32,
+}
 
 #[function_component(App)]
 fn app() -> Html {
-    let headers = vec!["Header 1".to_string(), "Header 2".to_string(), "Header 3".to_string()];
+    let columns = vec![
+        Column::new("Name", |user: &User| user.name.clone()),
+        Column::new("Age", |user: &User| user.age.to_string())
+            .with_comparator(|a: &User, b: &User| a.age.cmp(&b.age)),
+        Column::new("Profile", |user: &User| user.name.clone())
+            .with_renderer(|user: &User| html! { <a href={format!("/users/{}", user.name)}>{"View"}</a> }),
+    ];
     let data = vec![
-        vec!["Row 1, Cell 1".to_string(), "Row 1, Cell 2".to_string(), "Row 1, Cell 3".to_string()],
-        vec!["Row 2, Cell 1".to_string(), "Row 2, Cell 2".to_string(), "Row 2, Cell 3".to_string()],
+        User { name: "Alice".to_string(), age: 31 },
+        User { name: "Bob".to_string(), age: 9 },
     ];
 
     html! {
-        <Table headers={headers} data={data} table_width={Width::Full} />
+        <Table<User> columns={columns} data={data} table_width={Width::Full} />
     }
 }
                     "#}
@@ -46,8 +76,39 @@ fn app() -> Html {
                 <Subheadline>{"Properties"}</Subheadline>
                 <Paragraph>{"The Table component accepts numerous properties for customization:"}</Paragraph>
                 <ul>
-                    <li>{"headers: Vec<String> - The headers for the table."}</li>
-                    <li>{"data: Vec<Vec<String>> - The data for the table."}</li>
+                    <li>{"columns: Vec<Column<T>> - The columns of the table. A column has a header, an accessor returning the text value of a row, an optional renderer (with_renderer) and an optional comparator used for sorting (with_comparator)."}</li>
+                    <li>{"data: Vec<T> - The rows of the table."}</li>
+                    <li>{"on_sort: Option<Callback<(usize, SortDirection)>> - Called with the clicked column and its new direction."}</li>
+                    <li>{"on_sort_change: Option<Callback<Vec<SortKey>>> - Called with the complete new sort stack."}</li>
+                    <li>{"sort_mode: SortMode - Whether rows are sorted by the table (Client, the default) or by the server (Server)."}</li>
+                    <li>{"sort: Option<Vec<SortKey>> - The current sort when it is owned by the parent."}</li>
+                    <li>{"initial_sort: Vec<SortKey> - The sort the table starts with when sort is not set."}</li>
+                    <li>{"page_size: Option<usize> - Enables pagination kept by the table, starting with this page size."}</li>
+                    <li>{"pagination: Option<PaginationState> - The current pagination state when it is owned by the parent."}</li>
+                    <li>{"pagination_mode: PaginationMode - Whether rows are paged by the table (Client, the default) or by the server (Server)."}</li>
+                    <li>{"on_page_change: Option<Callback<PaginationState>> - Called when the page or page size changes."}</li>
+                    <li>{"page_sizes: Vec<usize> - The page sizes offered by the selector. Default is [10, 25, 50, 100]."}</li>
+                    <li>{"searchable: bool - Shows a search box above the table."}</li>
+                    <li>{"filter_mode: FilterMode - Whether rows are filtered by the table (Client, the default) or by the server (Server)."}</li>
+                    <li>{"filter: Option<TableFilter> - The current search and column filters when they are owned by the parent."}</li>
+                    <li>{"on_filter: Option<Callback<TableFilter>> - Called when the search or a column filter changes."}</li>
+                    <li>{"export_formats: Vec<ExportFormat> - Adds a download button per format (ExportFormat::Csv, ExportFormat::Json) above the table."}</li>
+                    <li>{"export_file_name: String - The name of exported files, without the extension. Default is \"table\"."}</li>
+                    <li>{"on_export: Option<Callback<(ExportFormat, String)>> - Called with the format and the serialized data of an export."}</li>
+                    <li>{"virtualize: Option<RowHeight> - Renders only the rows scrolled into view."}</li>
+                    <li>{"overscan: usize - Extra rows rendered around the visible rows of a virtualized table. Default is 5."}</li>
+                    <li>{"sticky_header: bool - Keeps the header at the top while the table scrolls."}</li>
+                    <li>{"frozen_columns: usize - The number of leading columns that stay in place while scrolling horizontally."}</li>
+                    <li>{"resizable_columns: bool - Lets columns be resized by dragging the right edge of their header."}</li>
+                    <li>{"on_column_resize: Option<Callback<(usize, f64)>> - Called with the column index and its new width in pixels."}</li>
+                    <li>{"selection: SelectionMode - None (the default), Single or Multi row selection."}</li>
+                    <li>{"row_key: Option<RowKey<T>> - Extracts the stable key of a row. Defaults to the row's index in data."}</li>
+                    <li>{"selected: Option<Vec<String>> - The keys of the selected rows when the selection is owned by the parent."}</li>
+                    <li>{"on_selection_change: Option<Callback<Vec<String>>> - Called with the keys of all selected rows."}</li>
+                    <li>{"row_detail: Option<RowDetail<T>> - Renders the panel shown below an expanded row."}</li>
+                    <li>{"expanded: Option<Vec<String>> - The keys of the expanded rows when they are owned by the parent."}</li>
+                    <li>{"on_expand_change: Option<Callback<Vec<String>>> - Called with the keys of all expanded rows."}</li>
+                    <li>{"group_by: Option<usize> - Groups the rows by the value of the column at this index."}</li>
                     <li>{"table_border_width: BorderWidth - The border width of the table."}</li>
                     <li>{"table_border_radius: BorderRadius - The border radius of the table."}</li>
                     <li>{"table_border_style: BorderStyle - The border style of the table."}</li>
@@ -62,14 +123,198 @@ fn app() -> Html {
                     <li>{"cell_overflow: Overflow - Overflow property for cells. Default is Overflow::Auto."}</li>
                 </ul>
 
+                <Subheadline>{"Sorting"}</Subheadline>
+                <Paragraph>{"Clicking a header sorts the table by that column, cycling through ascending, descending and unsorted. Shift-clicking a header adds the column to the current sort, so ties are broken by the columns sorted before it. The sort is stable, so rows that compare equal keep their original order."}</Paragraph>
+                <Paragraph>{"How a column compares its values is set with with_sort_kind: SortKind::Text (the default), CaseInsensitive, Natural (\"file9\" before \"file10\"), Locale, Numeric and Date (ISO 8601). Values that cannot be parsed as a number or date are placed after the valid ones. For anything else, with_comparator takes a custom comparator on the row type."}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+let columns = vec![
+    Column::new("Name", |user: &User| user.name.clone()).with_sort_kind(SortKind::Natural),
+    Column::new("Age", |user: &User| user.age.to_string()).with_sort_kind(SortKind::Numeric),
+    Column::new("Joined", |user: &User| user.joined.clone()).with_sort_kind(SortKind::Date),
+];
+                    "#}
+                    language="Rust"
+                />
+
+                <Paragraph>{"By default the table keeps its own sort, optionally starting from initial_sort. To own the sort in the parent, pass it in the sort prop and update it from on_sort_change. With sort_mode={SortMode::Server} the table never reorders its rows, so data sorted by your backend is rendered exactly as returned:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+let sort = use_state(|| vec![SortKey::new(0, SortDirection::Ascending)]);
+let on_sort_change = {
+    let sort = sort.clone();
+    Callback::from(move |new_sort: Vec<SortKey>| {
+        // Request the rows in the new order from the server here
+        sort.set(new_sort);
+    })
+};
+
+html! {
+    <Table<User>
+        columns={columns}
+        data={rows_from_server}
+        sort_mode={SortMode::Server}
+        sort={Some((*sort).clone())}
+        on_sort_change={on_sort_change}
+    />
+}
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Pagination"}</Subheadline>
+                <Paragraph>{"Set page_size to let the table paginate its rows. A Pagination control with a \"showing X–Y of Z\" summary, page buttons and a page-size selector is rendered below the table. For data paged by a server, pass the PaginationState in the pagination prop together with pagination_mode={PaginationMode::Server}; the rows are then taken as the current page and on_page_change tells you which page to fetch:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+// Paged by the table
+<Table<User> columns={columns.clone()} data={all_users} page_size={Some(25)} />
+
+// Paged by the server
+let page = use_state(|| PaginationState::new(25, total_from_server));
+let on_page_change = {
+    let page = page.clone();
+    Callback::from(move |new_page: PaginationState| {
+        // Fetch rows new_page.range() from the server here
+        page.set(new_page);
+    })
+};
+
+html! {
+    <Table<User>
+        columns={columns}
+        data={current_page_rows}
+        pagination={Some(*page)}
+        pagination_mode={PaginationMode::Server}
+        on_page_change={on_page_change}
+    />
+}
+                    "#}
+                    language="Rust"
+                />
+                <Paragraph>{"The Pagination component can also be used on its own, e.g. for lists or card grids: <Pagination state={state} on_change={on_change} />."}</Paragraph>
+
+                <Subheadline>{"Filtering"}</Subheadline>
+                <Paragraph>{"Columns created with_filter get a filter input in a second header row: FilterKind::Text keeps rows containing the text, FilterKind::Select offers the distinct values of the column and FilterKind::Range takes a numeric minimum and maximum. searchable adds a search box above the table that searches all columns. Rows are filtered before they are sorted and paged. For server-side filtering, set filter_mode={FilterMode::Server} and request the rows from on_filter:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+let columns = vec![
+    Column::new("Name", |user: &User| user.name.clone()).with_filter(FilterKind::Text),
+    Column::new("Role", |user: &User| user.role.clone()).with_filter(FilterKind::Select),
+    Column::new("Age", |user: &User| user.age.to_string())
+        .with_sort_kind(SortKind::Numeric)
+        .with_filter(FilterKind::Range),
+];
+
+html! {
+    <Table<User>
+        columns={columns}
+        data={data}
+        searchable={true}
+        on_filter={Callback::from(|filter: TableFilter| log::info!("{:?}", filter))}
+    />
+}
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Export"}</Subheadline>
+                <Paragraph>{"export_formats adds buttons that download the rows passing the filters, in the order they are sorted and across all pages, as RFC 4180 CSV or JSON. The serializers are plain functions (to_csv, to_json and ExportFormat::serialize), so the same output can be produced and tested outside the browser:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+<Table<User>
+    columns={columns.clone()}
+    data={data}
+    searchable={true}
+    export_formats={vec![ExportFormat::Csv, ExportFormat::Json]}
+    export_file_name={"users"}
+/>
+
+let csv = to_csv(&data.iter().collect::<Vec<_>>(), &columns);
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Virtualization"}</Subheadline>
+                <Paragraph>{"For tens of thousands of rows, set virtualize to the height of a row. Only the rows scrolled into view (plus overscan rows on each side) are rendered, and spacer rows keep the scrollbar accurate. The table scrolls inside a container sized by table_height and table_max_height. Use RowHeight::Fixed when every row has the same height, or RowHeight::Estimated to let the table measure the rendered rows. The List component accepts the same virtualize and overscan properties."}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+<Table<User>
+    columns={columns}
+    data={fifty_thousand_users}
+    virtualize={Some(RowHeight::Fixed(36.0))}
+    table_height={Height::Large}
+/>
+
+<List data={items} virtualize={Some(RowHeight::Estimated(24.0))} height={Height::Medium} />
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Sticky, Frozen and Resizable Columns"}</Subheadline>
+                <Paragraph>{"sticky_header keeps the header visible while scrolling through a table limited by table_height or table_max_height, and frozen_columns keeps the first columns (and the selection column) in place while scrolling sideways. With resizable_columns, dragging the right edge of a header resizes its column. Store the widths reported by on_column_resize and pass them back with Column::with_width to restore the layout later:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+let columns = vec![
+    Column::new("Name", |user: &User| user.name.clone()).with_width(saved_widths[0]),
+    Column::new("Age", |user: &User| user.age.to_string()).with_width(saved_widths[1]),
+];
+
+<Table<User>
+    columns={columns}
+    data={data}
+    sticky_header={true}
+    frozen_columns={1}
+    resizable_columns={true}
+    on_column_resize={Callback::from(|(column, width): (usize, f64)| save_width(column, width))}
+    table_max_height={Height::Large}
+/>
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Selection"}</Subheadline>
+                <Paragraph>{"Set selection to SelectionMode::Single or SelectionMode::Multi to add a leading checkbox column. In multi mode the header checkbox selects or deselects the rows currently shown (respecting the current page), and shift-clicking a checkbox selects every row since the previously clicked one. Rows are identified by row_key, so the selection survives sorting and paging; on_selection_change receives the keys of all selected rows:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+<Table<User>
+    columns={columns}
+    data={data}
+    selection={SelectionMode::Multi}
+    row_key={Some(RowKey::new(|user: &User| user.id.to_string()))}
+    on_selection_change={Callback::from(|keys: Vec<String>| log::info!("Selected {:?}", keys))}
+/>
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Expandable Rows and Grouping"}</Subheadline>
+                <Paragraph>{"A row_detail adds a toggle to every row that expands a detail panel below it. group_by groups the rows by a column under collapsible headers showing the number of rows, and columns created with_aggregate show their Count or Sum for each group. Groups are ordered in the direction the grouping column is sorted in, and the rows of a group follow the rest of the sort. With sort_mode={SortMode::Server} the rows are grouped in the order they arrive, so return the rows of a group next to each other:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+let columns = vec![
+    Column::new("Team", |user: &User| user.team.clone()),
+    Column::new("Name", |user: &User| user.name.clone()).with_aggregate(Aggregate::Count),
+    Column::new("Hours", |user: &User| user.hours.to_string()).with_aggregate(Aggregate::Sum),
+];
+
+<Table<User>
+    columns={columns}
+    data={data}
+    group_by={Some(0)}
+    row_detail={Some(RowDetail::new(|user: &User| html! { <p>{&user.bio}</p> }))}
+/>
+                    "#}
+                    language="Rust"
+                />
+
                 <Subheadline>{"Customization Examples"}</Subheadline>
                 
                 <Paragraph>{"1. Customizing Table Width and Border:"}</Paragraph>
                 <CodeBlock 
                     snippet={r#"
-<Table 
-    headers={headers} 
-    data={data} 
+<Table<User>
+    columns={columns}
+    data={data}
     table_width={Width::Full}
     table_border_width={BorderWidth::Medium}
     table_border_style={BorderStyle::Solid}
@@ -81,9 +326,9 @@ fn app() -> Html {
                 <Paragraph>{"2. Customizing Cell Properties:"}</Paragraph>
                 <CodeBlock 
                     snippet={r#"
-<Table 
-    headers={headers} 
-    data={data} 
+<Table<User>
+    columns={columns}
+    data={data}
     cell_padding={Height::Small}
     cell_text_align={TextAlign::Center}
     cell_overflow={Overflow::Hidden}
@@ -95,7 +340,7 @@ fn app() -> Html {
                 <Subheadline>{"Live Example"}</Subheadline>
                 <Paragraph>{"Here's a live example of the Table component:"}</Paragraph>
 
-                <Table headers={headers} data={data} table_width={Width::Full} />
+                <Table<User> columns={columns} data={data} table_width={Width::Full} />
 
                 <Subheadline>{"Implementation Details"}</Subheadline>
                 <Paragraph>{"The Table component uses Yew's classes macro to apply styling based on the provided properties. It generates the table structure using HTML table elements (<table>, <thead>, <tbody>, <tr>, <th>, <td>) and applies the appropriate classes to each element."}</Paragraph>
@@ -103,11 +348,11 @@ fn app() -> Html {
                 <Subheadline>{"Best Practices"}</Subheadline>
                 <Paragraph>{"When using the Table component, consider the following best practices:"}</Paragraph>
                 <ul>
-                    <li>{"Ensure that the number of items in each data row matches the number of headers."}</li>
+                    <li>{"Keep your domain types as rows and use renderers for interactive cells instead of stringifying the data up front."}</li>
                     <li>{"Use appropriate width settings to ensure the table fits well within its container."}</li>
                     <li>{"Consider using cell_overflow when dealing with potentially long content in cells."}</li>
                     <li>{"Use consistent styling across your tables for a uniform look in your application."}</li>
-                    <li>{"For large datasets, consider implementing pagination or virtualization to improve performance."}</li>
+                    <li>{"For large datasets, use page_size, server-side pagination or virtualize to keep the number of rendered rows small."}</li>
                 </ul>
 
                 <Paragraph>{"The Table component provides a flexible way to display tabular data in your Zirv UI application, with extensive customization options to fit various design needs."}</Paragraph>
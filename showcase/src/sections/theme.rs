@@ -31,12 +31,29 @@ let custom_theme = Theme::default()
                     language="Rust"
                 />
 
+                <Subheadline>{"Validating Colors"}</Subheadline>
+                <Paragraph>{"The set_ methods log a warning and keep the previous color when a color cannot be parsed. The text color setters also accept CSS variables such as var(--brand) and keywords such as inherit. For colors coming from configuration or user input, use Theme::builder, which validates every color and returns a ThemeError naming the field and the rejected input:"}</Paragraph>
+                <CodeBlock
+                    snippet={r##"
+match Theme::builder().with_primary_color(&config.primary).with_error_color("#e74c3c").build() {
+    Ok(theme) => theme,
+    Err(error) => {
+        // e.g. invalid color "rgb(255,0" for `primary_color`: ...
+        log::warn!("{}", error);
+        Theme::default()
+    }
+}
+                    "##}
+                    language="Rust"
+                />
+
                 <Subheadline>{"Theme Properties"}</Subheadline>
                 <Paragraph>{"The Theme struct includes the following properties, each with a regular, dark, and light variant:"}</Paragraph>
                 <ul>
                     <li>{"background_color"}</li>
                     <li>{"module_color"}</li>
                     <li>{"text_color_primary and text_color_secondary"}</li>
+                    <li>{"on_primary_color and on_secondary_color (derived, without variants)"}</li>
                     <li>{"primary_color"}</li>
                     <li>{"secondary_color"}</li>
                     <li>{"tertiary_color"}</li>
@@ -54,13 +71,13 @@ let custom_theme = Theme::default()
     .set_background_color("rgb(37,46,66)")
     .set_primary_color("rgb(44,105,141)")
     .set_success_color("rgb(46,204,113)")
-    .set_error_color("rgb(231,76,60)");
+    .set_error_color("rgb(238,96,82)");
                     "#}
                     language="Rust"
                 />
 
                 <Subheadline>{"Color Variants"}</Subheadline>
-                <Paragraph>{"When setting a color, the Theme automatically generates dark and light variants:"}</Paragraph>
+                <Paragraph>{"When setting a color, the Theme automatically generates dark and light variants with a lower and higher perceived lightness:"}</Paragraph>
                 <CodeBlock 
                     snippet={r#"
 // This will set primary_color, primary_color_dark, and primary_color_light
@@ -69,6 +86,42 @@ let custom_theme = Theme::default()
                     language="Rust"
                 />
 
+                <Subheadline>{"Shades"}</Subheadline>
+                <Paragraph>{"Every palette color (background, module, primary, secondary, tertiary, success, warning, error and disabled) also gets a scale of shades from 50 (lightest) to 900 (darkest). The shades are generated in the OKLCH color space, so they keep the hue of the color, are evenly spaced in perceived lightness and line up across colors. They are available as CSS variables and through the BackgroundColor::Shade and TextColor::Shade options:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+<Button
+    background_color={BackgroundColor::Shade(Palette::Primary, Shade::S100)}
+    font_color={TextColor::Shade(Palette::Primary, Shade::S800)}
+>
+    {"Tinted button"}
+</Button>
+
+.callout {
+    background-color: var(--color-primary-100);
+    border-color: var(--color-primary-300);
+}
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Contrast"}</Subheadline>
+                <Paragraph>{"Setting the primary or secondary color also derives on_primary_color and on_secondary_color, choosing white or black text by WCAG contrast. Buttons use TextColor::OnPrimary by default, so a light primary color gets dark button text. Theme::audit lists every color pair used by the built-in components that misses the WCAG AA contrast ratio (4.5 for text, 3.0 for icons):"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+let theme = Theme::default().set_primary_color("rgb(255,235,59)");
+assert_eq!(theme.on_primary_color, "rgb(0,0,0)");
+
+for issue in theme.audit() {
+    log::warn!(
+        "{}: {} on {} has a contrast of {:.2}, {} is required",
+        issue.usage, issue.foreground, issue.background, issue.ratio, issue.required
+    );
+}
+                    "#}
+                    language="Rust"
+                />
+
                 <Subheadline>{"ThemeProvider Component"}</Subheadline>
                 <Paragraph>{"The ThemeProvider component applies the provided theme to its children by injecting CSS variables:"}</Paragraph>
                 <CodeBlock 
@@ -88,6 +141,57 @@ html! {
                     language="Rust"
                 />
 
+                <Subheadline>{"Light and Dark Mode"}</Subheadline>
+                <Paragraph>{"Pass a dark_theme next to theme to switch between a light and a dark theme. By default the provider follows the prefers-color-scheme setting of the operating system and updates as soon as it changes. Components inside the provider can read the mode with the use_theme hook and override it; the override is stored in localStorage (under storage_key) and ThemeMode::System removes it again:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+use zirv_ui::{use_theme, Theme, ThemeMode, ThemeProvider};
+
+html! {
+    <ThemeProvider theme={light_theme} dark_theme={dark_theme}>
+        <YourApp />
+    </ThemeProvider>
+}
+
+#[function_component(ModeToggle)]
+fn mode_toggle() -> Html {
+    let theme = use_theme();
+    let next = if theme.is_dark { ThemeMode::Light } else { ThemeMode::Dark };
+    let onclick = theme.set_mode.reform(move |_: MouseEvent| next);
+
+    html! { <button {onclick}>{"Toggle dark mode"}</button> }
+}
+                    "#}
+                    language="Rust"
+                />
+
+                <Subheadline>{"Nested Themes"}</Subheadline>
+                <Paragraph>{"Every ThemeProvider wraps its children in an element with a generated scope class and declares its variables for that class only, so a single panel can be themed differently from the rest of the page. The outermost provider also declares them on :root for the page background and body text. A nested provider without a theme inherits the theme of the enclosing provider and applies its overrides on top; use_theme().theme returns the theme applied to a component:"}</Paragraph>
+                <CodeBlock
+                    snippet={r#"
+use zirv_ui::{use_theme, Theme, ThemeField, ThemeProvider};
+
+html! {
+    <ThemeProvider theme={app_theme}>
+        <YourApp />
+        <ThemeProvider overrides={vec![
+            (ThemeField::PrimaryColor, "rgb(142,68,173)".to_string()),
+            (ThemeField::ModuleColor, "rgb(44,35,58)".to_string()),
+        ]}>
+            <Sidebar />
+        </ThemeProvider>
+    </ThemeProvider>
+}
+
+#[function_component(Swatch)]
+fn swatch() -> Html {
+    let theme = use_theme().theme;
+    html! { <span>{ theme.primary_color }</span> }
+}
+                    "#}
+                    language="Rust"
+                />
+
                 <Subheadline>{"Using Theme Variables"}</Subheadline>
                 <Paragraph>{"Once a theme is applied using ThemeProvider, you can use the CSS variables in your styles:"}</Paragraph>
                 <CodeBlock 
@@ -99,7 +203,7 @@ html! {
 
 .primary-button {
     background-color: var(--color-primary);
-    color: var(--text-color-primary);
+    color: var(--text-color-on-primary);
 }
                     "#}
                     language="CSS"
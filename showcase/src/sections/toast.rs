@@ -68,8 +68,10 @@ fn app() -> Html {
                 <Paragraph>{"The Toast system supports different types of notifications:"}</Paragraph>
                 <ul>
                     <li>{"ToastType::Info: For general informational messages."}</li>
+                    <li>{"ToastType::Success: For confirming that an operation succeeded."}</li>
                     <li>{"ToastType::Warn: For warning messages."}</li>
                     <li>{"ToastType::Error: For error messages."}</li>
+                    <li>{"ToastType::Custom(class): For your own notification styles, using the given CSS class."}</li>
                 </ul>
 
                 <Subheadline>{"Customization Examples"}</Subheadline>
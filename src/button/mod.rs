@@ -103,9 +103,10 @@ pub struct ButtonProps {
     /// The background color of the button. Default is `BackgroundColor::Primary`.
     #[prop_or(BackgroundColor::Primary)]
     pub background_color: BackgroundColor,
-    /// The font color of the button text. Default is `TextColor::TextPrimary`.
-    #[prop_or(TextColor::TextPrimary)]
-    pub font_color: TextColor,
+    /// The font color of the button text. Default is the text color shown on `background_color`,
+    /// see `BackgroundColor::on_color`.
+    #[prop_or_default]
+    pub font_color: Option<TextColor>,
     /// The font size of the button text.
     #[prop_or_default]
     pub font_size: FontSize,
@@ -131,7 +132,8 @@ pub struct ButtonProps {
 /// - `padding`: The padding inside the button.
 /// - `margin`: The margin outside the button.
 /// - `background_color`: The background color of the button. Default is `BackgroundColor::Primary`.
-/// - `font_color`: The font color of the button text. Default is `TextColor::TextPrimary`.
+/// - `font_color`: The font color of the button text. Default is the text color shown on
+///   `background_color`, e.g. `TextColor::OnSecondary` for `BackgroundColor::Secondary`.
 /// - `font_size`: The font size of the button text.
 /// - `classes`: Additional CSS classes to apply to the button.
 /// - `onclick`: Callback to be executed when the button is clicked.
@@ -165,6 +167,8 @@ pub fn button(props: &ButtonProps) -> Html {
         })
     };
 
+    let font_color = props.font_color.clone().unwrap_or_else(|| props.background_color.on_color());
+
     let classes = classes!(
         &props.width,
         &props.height,
@@ -176,7 +180,7 @@ pub fn button(props: &ButtonProps) -> Html {
         &props.padding,
         &props.margin,
         background_color(),
-        &props.font_color,
+        &font_color,
         &props.font_size,
         Some(props.classes.clone())
     );
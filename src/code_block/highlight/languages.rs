@@ -0,0 +1,140 @@
+//! Language Definitions Module
+//!
+//! This module contains the lexical rules of every language supported by the highlighter. Each
+//! language is gated behind its own `highlight_*` cargo feature, so applications only pay for the
+//! languages they actually display.
+
+use super::lexer::Rules;
+
+/// Rules for Rust.
+#[cfg(feature = "highlight_rust")]
+pub const RUST: Rules = Rules {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "fn", "for",
+        "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+        "static", "struct", "super", "trait", "type", "unsafe", "use", "where", "while",
+    ],
+    literals: &["true", "false", "None", "Some", "Ok", "Err"],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    string_delimiters: &['"', '\''],
+    lifetimes: true,
+    punctuation: "{}[]()<>.,;:=+-*/%&|^!?#@",
+    identifier_start: "",
+    identifier_continue: "",
+};
+
+/// Rules for TOML.
+#[cfg(feature = "highlight_toml")]
+pub const TOML: Rules = Rules {
+    keywords: &[],
+    literals: &["true", "false", "inf", "nan"],
+    line_comments: &["#"],
+    block_comment: None,
+    string_delimiters: &['"', '\''],
+    lifetimes: false,
+    punctuation: "{}[]=,.",
+    identifier_start: "",
+    identifier_continue: "-",
+};
+
+/// Rules for JSON.
+#[cfg(feature = "highlight_json")]
+pub const JSON: Rules = Rules {
+    keywords: &[],
+    literals: &["true", "false", "null"],
+    line_comments: &[],
+    block_comment: None,
+    string_delimiters: &['"'],
+    lifetimes: false,
+    punctuation: "{}[]:,-",
+    identifier_start: "",
+    identifier_continue: "",
+};
+
+/// Rules for CSS.
+#[cfg(feature = "highlight_css")]
+pub const CSS: Rules = Rules {
+    keywords: &["@charset", "@font-face", "@import", "@keyframes", "@layer", "@media", "@supports", "important"],
+    literals: &["auto", "inherit", "initial", "none", "unset"],
+    line_comments: &[],
+    block_comment: Some(("/*", "*/")),
+    string_delimiters: &['"', '\''],
+    lifetimes: false,
+    punctuation: "{}[]():;,.>+~*#!=",
+    identifier_start: "@",
+    identifier_continue: "-",
+};
+
+/// Rules for JavaScript and TypeScript.
+#[cfg(feature = "highlight_js")]
+pub const JS: Rules = Rules {
+    keywords: &[
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "of",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "try",
+        "type",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    literals: &["true", "false", "null", "undefined", "NaN", "Infinity"],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    string_delimiters: &['"', '\'', '`'],
+    lifetimes: false,
+    punctuation: "{}[]()<>.,;:=+-*/%&|^!?~",
+    identifier_start: "$",
+    identifier_continue: "$",
+};
+
+/// Rules for POSIX shells such as `sh` and `bash`.
+#[cfg(feature = "highlight_shell")]
+pub const SHELL: Rules = Rules {
+    keywords: &[
+        "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in", "local", "return",
+        "select", "then", "until", "while",
+    ],
+    literals: &["true", "false"],
+    line_comments: &["#"],
+    block_comment: None,
+    string_delimiters: &['"', '\''],
+    lifetimes: false,
+    punctuation: "{}[]();|&<>=",
+    identifier_start: "$",
+    identifier_continue: "-",
+};
@@ -0,0 +1,155 @@
+//! Generic Lexer Module
+//!
+//! This module provides the table-driven lexer shared by most of the supported languages. A
+//! language is described by a set of `Rules` (keywords, comment markers, string delimiters and so
+//! on), which the lexer uses to split a snippet into a flat list of `Token`s.
+
+use super::{
+    push_token,
+    Token,
+    TokenKind,
+};
+
+/// Describes the lexical structure of a language for the generic lexer.
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+    /// Reserved words rendered as keywords.
+    pub keywords: &'static [&'static str],
+    /// Built-in values such as `true`, `false` and `null`.
+    pub literals: &'static [&'static str],
+    /// Markers that start a comment running to the end of the line.
+    pub line_comments: &'static [&'static str],
+    /// Start and end markers of a block comment.
+    pub block_comment: Option<(&'static str, &'static str)>,
+    /// Characters that open and close a string.
+    pub string_delimiters: &'static [char],
+    /// Whether a `'` that does not form a character literal starts a lifetime or label.
+    pub lifetimes: bool,
+    /// Characters rendered as punctuation.
+    pub punctuation: &'static str,
+    /// Characters besides letters and `_` that may start an identifier.
+    pub identifier_start: &'static str,
+    /// Characters besides alphanumerics and `_` that may continue an identifier.
+    pub identifier_continue: &'static str,
+}
+
+/// Splits `code` into tokens according to `rules`.
+///
+/// The lexer never fails: anything it does not recognise is emitted as `TokenKind::Plain`, and
+/// unterminated strings or comments run to the end of the snippet.
+///
+/// # Arguments
+///
+/// * `code` - The snippet to tokenize
+/// * `rules` - The lexical rules of the snippet's language
+///
+/// # Returns
+///
+/// The tokens of the snippet, which concatenate back to `code`
+pub fn tokenize<'a>(code: &'a str, rules: &Rules) -> Vec<Token<'a>> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while let Some(c) = code[pos..].chars().next() {
+        let rest = &code[pos..];
+
+        let (kind, len) = if c.is_whitespace() {
+            (TokenKind::Plain, take_while(rest, char::is_whitespace))
+        } else if rules.line_comments.iter().any(|marker| rest.starts_with(marker)) {
+            (TokenKind::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else if let Some(len) = block_comment(rest, rules) {
+            (TokenKind::Comment, len)
+        } else if c == '\'' && rules.lifetimes && !is_char_literal(rest) {
+            (TokenKind::Plain, 1 + take_while(&rest[1..], |c| is_identifier_continue(c, rules)))
+        } else if rules.string_delimiters.contains(&c) {
+            (TokenKind::String, string(rest, c))
+        } else if c.is_ascii_digit() {
+            (TokenKind::Number, number(rest))
+        } else if is_identifier_start(c, rules) {
+            let len = c.len_utf8() + take_while(&rest[c.len_utf8()..], |c| is_identifier_continue(c, rules));
+            let word = &rest[..len];
+
+            if rules.keywords.contains(&word) {
+                (TokenKind::Keyword, len)
+            } else if rules.literals.contains(&word) {
+                (TokenKind::Literal, len)
+            } else {
+                (TokenKind::Plain, len)
+            }
+        } else if rules.punctuation.contains(c) {
+            (TokenKind::Punctuation, c.len_utf8())
+        } else {
+            (TokenKind::Plain, c.len_utf8())
+        };
+
+        push_token(&mut tokens, kind, &rest[..len]);
+        pos += len;
+    }
+
+    tokens
+}
+
+/// Returns the byte length of the longest prefix of `text` whose characters satisfy `predicate`.
+fn take_while(text: &str, predicate: impl Fn(char) -> bool) -> usize {
+    text.find(|c: char| !predicate(c)).unwrap_or(text.len())
+}
+
+/// Returns the byte length of the block comment at the start of `text`, if there is one.
+fn block_comment(text: &str, rules: &Rules) -> Option<usize> {
+    let (start, end) = rules.block_comment?;
+    if !text.starts_with(start) {
+        return None;
+    }
+
+    let len = text[start.len()..].find(end).map_or(text.len(), |index| start.len() + index + end.len());
+    Some(len)
+}
+
+/// Returns the byte length of the string opened by `delimiter` at the start of `text`.
+fn string(text: &str, delimiter: char) -> usize {
+    let mut chars = text.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if c == delimiter {
+            return index + c.len_utf8();
+        }
+    }
+
+    text.len()
+}
+
+/// Returns the byte length of the number at the start of `text`, including suffixes such as
+/// `u32`, `px` or `e10`.
+fn number(text: &str) -> usize {
+    let mut len = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let is_fraction = c == '.' && chars.peek().is_some_and(char::is_ascii_digit);
+        if !(c.is_ascii_alphanumeric() || c == '_' || is_fraction) {
+            break;
+        }
+        len += c.len_utf8();
+    }
+
+    len
+}
+
+/// Checks whether the `'` at the start of `text` opens a character literal such as `'a'` or
+/// `'\n'` rather than a lifetime.
+fn is_char_literal(text: &str) -> bool {
+    let mut chars = text.chars().skip(1);
+    match chars.next() {
+        Some('\\') => true,
+        Some(_) => chars.next() == Some('\''),
+        None => false,
+    }
+}
+
+fn is_identifier_start(c: char, rules: &Rules) -> bool {
+    c.is_alphabetic() || c == '_' || rules.identifier_start.contains(c)
+}
+
+fn is_identifier_continue(c: char, rules: &Rules) -> bool {
+    c.is_alphanumeric() || c == '_' || rules.identifier_continue.contains(c)
+}
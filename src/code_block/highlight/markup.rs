@@ -0,0 +1,120 @@
+//! Markup Lexer Module
+//!
+//! This module provides the lexer for HTML, whose structure (tags, attributes and text content)
+//! does not fit the generic keyword based lexer. The contents of `<script>` and `<style>` elements
+//! are handed to the JavaScript and CSS lexers when those languages are enabled.
+
+use super::{
+    push_token,
+    Token,
+    TokenKind,
+};
+
+/// Splits an HTML snippet into tokens.
+///
+/// # Arguments
+///
+/// * `code` - The snippet to tokenize
+///
+/// # Returns
+///
+/// The tokens of the snippet, which concatenate back to `code`
+pub fn tokenize(code: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < code.len() {
+        let rest = &code[pos..];
+
+        if rest.starts_with("<!--") {
+            let len = rest.find("-->").map_or(rest.len(), |index| index + 3);
+            push_token(&mut tokens, TokenKind::Comment, &rest[..len]);
+            pos += len;
+        } else if is_tag_start(rest) {
+            let (len, name, has_body) = tag(rest, &mut tokens);
+            pos += len;
+
+            if has_body && (name.eq_ignore_ascii_case("script") || name.eq_ignore_ascii_case("style")) {
+                let body = &code[pos..];
+                let body_len = body.find(&format!("</{}", name)).unwrap_or(body.len());
+                embedded(name, &body[..body_len], &mut tokens);
+                pos += body_len;
+            }
+        } else {
+            // Skip the first character so that a stray `<` does not stop the text run
+            let len = rest.char_indices().skip(1).find(|(_, c)| *c == '<').map_or(rest.len(), |(index, _)| index);
+            push_token(&mut tokens, TokenKind::Plain, &rest[..len]);
+            pos += len;
+        }
+    }
+
+    tokens
+}
+
+/// Checks whether `text` starts with an opening tag, a closing tag or a declaration.
+fn is_tag_start(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next() == Some('<') && chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!')
+}
+
+/// Tokenizes the tag at the start of `text`.
+///
+/// Returns the byte length of the tag, its name and whether it opens an element with a body (as
+/// opposed to a closing or self-closing tag).
+fn tag<'a>(text: &'a str, tokens: &mut Vec<Token<'a>>) -> (usize, &'a str, bool) {
+    let closing = text.starts_with("</");
+    let mut pos = if closing { 2 } else { 1 };
+    push_token(tokens, TokenKind::Punctuation, &text[..pos]);
+
+    let name_len = take_while(&text[pos..], |c| c.is_ascii_alphanumeric() || matches!(c, '-' | ':' | '!'));
+    let name = &text[pos..pos + name_len];
+    push_token(tokens, TokenKind::Tag, name);
+    pos += name_len;
+
+    while let Some(c) = text[pos..].chars().next() {
+        let rest = &text[pos..];
+
+        if c == '>' || rest.starts_with("/>") {
+            let len = if c == '>' { 1 } else { 2 };
+            push_token(tokens, TokenKind::Punctuation, &rest[..len]);
+            return (pos + len, name, !closing && c == '>');
+        }
+
+        let (kind, len) = if c.is_whitespace() {
+            (TokenKind::Plain, take_while(rest, char::is_whitespace))
+        } else if c == '=' {
+            (TokenKind::Punctuation, 1)
+        } else if c == '"' || c == '\'' {
+            (TokenKind::String, rest[1..].find(c).map_or(rest.len(), |index| index + 2))
+        } else if tokens.last().is_some_and(|token| token.text == "=") {
+            (TokenKind::String, take_while(rest, |c| !c.is_whitespace() && c != '>'))
+        } else {
+            (TokenKind::Attribute, take_while(rest, |c| !c.is_whitespace() && !matches!(c, '=' | '>' | '/')).max(1))
+        };
+
+        push_token(tokens, kind, &rest[..len]);
+        pos += len;
+    }
+
+    (pos, name, false)
+}
+
+/// Tokenizes the body of a `<script>` or `<style>` element.
+fn embedded<'a>(element: &str, body: &'a str, tokens: &mut Vec<Token<'a>>) {
+    let embedded_tokens = match element.to_ascii_lowercase().as_str() {
+        #[cfg(feature = "highlight_js")]
+        "script" => super::lexer::tokenize(body, &super::languages::JS),
+        #[cfg(feature = "highlight_css")]
+        "style" => super::lexer::tokenize(body, &super::languages::CSS),
+        _ => vec![Token { kind: TokenKind::Plain, text: body }],
+    };
+
+    for token in embedded_tokens {
+        push_token(tokens, token.kind, token.text);
+    }
+}
+
+/// Returns the byte length of the longest prefix of `text` whose characters satisfy `predicate`.
+fn take_while(text: &str, predicate: impl Fn(char) -> bool) -> usize {
+    text.find(|c: char| !predicate(c)).unwrap_or(text.len())
+}
@@ -0,0 +1,295 @@
+//! Code Highlighting Module
+//!
+//! This module provides syntax highlighting for code snippets. Snippets are split into tokens
+//! (keywords, strings, comments, numbers, punctuation, ...) by a small pure-Rust lexer and rendered
+//! as `<span>` elements carrying a `token-*` class, which the stylesheet colours using the theme
+//! variables.
+//!
+//! Every language is gated behind its own cargo feature, so no grammar is compiled into the
+//! application unless it is needed:
+//!
+//! | Feature            | Language identifiers                          |
+//! |--------------------|-----------------------------------------------|
+//! | `highlight_rust`   | `rust`, `rs`                                  |
+//! | `highlight_toml`   | `toml`                                        |
+//! | `highlight_json`   | `json`                                        |
+//! | `highlight_html`   | `html`, `htm`, `xml`, `svg`                   |
+//! | `highlight_css`    | `css`                                         |
+//! | `highlight_js`     | `javascript`, `js`, `jsx`, `typescript`, `ts` |
+//! | `highlight_shell`  | `shell`, `sh`, `bash`, `zsh`                  |
+//!
+//! Snippets in any other language are rendered as escaped plain text.
+
+#[cfg(any(
+    feature = "highlight_rust",
+    feature = "highlight_toml",
+    feature = "highlight_json",
+    feature = "highlight_css",
+    feature = "highlight_js",
+    feature = "highlight_shell",
+))]
+pub mod languages;
+#[cfg(any(
+    feature = "highlight_rust",
+    feature = "highlight_toml",
+    feature = "highlight_json",
+    feature = "highlight_css",
+    feature = "highlight_js",
+    feature = "highlight_shell",
+))]
+pub mod lexer;
+#[cfg(feature = "highlight_html")]
+pub mod markup;
+
+/// The kind of a token produced by the lexer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Text without any highlighting, such as identifiers and whitespace.
+    Plain,
+    /// A reserved word of the language.
+    Keyword,
+    /// A built-in value such as `true` or `null`.
+    Literal,
+    /// A string or character literal.
+    String,
+    /// A line or block comment.
+    Comment,
+    /// A numeric literal.
+    Number,
+    /// Operators, brackets and separators.
+    Punctuation,
+    /// The name of a markup tag.
+    Tag,
+    /// The name of a markup attribute.
+    Attribute,
+}
+
+impl TokenKind {
+    /// Returns the CSS class used to style tokens of this kind, or `None` for plain text.
+    pub fn class(&self) -> Option<&'static str> {
+        match self {
+            TokenKind::Plain => None,
+            TokenKind::Keyword => Some("token-keyword"),
+            TokenKind::Literal => Some("token-literal"),
+            TokenKind::String => Some("token-string"),
+            TokenKind::Comment => Some("token-comment"),
+            TokenKind::Number => Some("token-number"),
+            TokenKind::Punctuation => Some("token-punctuation"),
+            TokenKind::Tag => Some("token-tag"),
+            TokenKind::Attribute => Some("token-attribute"),
+        }
+    }
+}
+
+/// A slice of a code snippet together with its kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// The kind of the token.
+    pub kind: TokenKind,
+    /// The source text of the token.
+    pub text: &'a str,
+}
+
+/// A function splitting a snippet of one particular language into tokens.
+pub type Lexer = fn(&str) -> Vec<Token<'_>>;
+
+/// Looks up the lexer for a language identifier such as `"rust"` or `"JSON"`.
+///
+/// Identifiers are matched case-insensitively. Returns `None` when the language is unknown or its
+/// `highlight_*` feature is disabled.
+///
+/// # Example
+///
+/// ```
+/// use zirv_ui::code_block::highlight::lexer_for;
+///
+/// assert!(lexer_for("Rust").is_some());
+/// assert!(lexer_for("brainfuck").is_none());
+/// ```
+pub fn lexer_for(language: &str) -> Option<Lexer> {
+    match language.trim().to_ascii_lowercase().as_str() {
+        #[cfg(feature = "highlight_rust")]
+        "rust" | "rs" => Some(|code| lexer::tokenize(code, &languages::RUST)),
+        #[cfg(feature = "highlight_toml")]
+        "toml" => Some(|code| lexer::tokenize(code, &languages::TOML)),
+        #[cfg(feature = "highlight_json")]
+        "json" => Some(|code| lexer::tokenize(code, &languages::JSON)),
+        #[cfg(feature = "highlight_html")]
+        "html" | "htm" | "xml" | "svg" => Some(markup::tokenize),
+        #[cfg(feature = "highlight_css")]
+        "css" => Some(|code| lexer::tokenize(code, &languages::CSS)),
+        #[cfg(feature = "highlight_js")]
+        "javascript" | "js" | "jsx" | "typescript" | "ts" => Some(|code| lexer::tokenize(code, &languages::JS)),
+        #[cfg(feature = "highlight_shell")]
+        "shell" | "sh" | "bash" | "zsh" => Some(|code| lexer::tokenize(code, &languages::SHELL)),
+        _ => None,
+    }
+}
+
+/// Splits a code snippet into tokens.
+///
+/// Snippets in an unsupported language are returned as a single `TokenKind::Plain` token.
+///
+/// # Arguments
+///
+/// * `code` - A string slice containing the code to be tokenized
+/// * `language` - A string slice specifying the programming language of the code
+///
+/// # Returns
+///
+/// The tokens of the snippet, which concatenate back to `code`
+///
+/// # Example
+///
+/// ```
+/// use zirv_ui::code_block::highlight::{
+///     tokenize,
+///     Token,
+///     TokenKind,
+/// };
+///
+/// let tokens = tokenize("{\"ok\": true}", "json");
+/// assert_eq!(tokens[1], Token { kind: TokenKind::String, text: "\"ok\"" });
+/// assert_eq!(tokens[4], Token { kind: TokenKind::Literal, text: "true" });
+/// ```
+pub fn tokenize<'a>(code: &'a str, language: &str) -> Vec<Token<'a>> {
+    match lexer_for(language) {
+        Some(lexer) => lexer(code),
+        None => vec![Token { kind: TokenKind::Plain, text: code }],
+    }
+}
+
+/// Highlights code with syntax highlighting
+///
+/// This function tokenizes a code snippet and renders each token as HTML-escaped text, wrapping
+/// highlighted tokens in a `<span>` with the class of their `TokenKind`.
+///
+/// # Arguments
+///
+/// * `code` - A string slice containing the code to be highlighted
+/// * `language` - A string slice specifying the programming language of the code
+///
+/// # Returns
+///
+/// A `String` containing the HTML representation of the highlighted code
+///
+/// # Example
+///
+/// ```
+/// use zirv_ui::code_block::highlight::highlight_code;
+///
+/// let highlighted = highlight_code("let x = \"<\";", "rust");
+/// assert_eq!(
+///     highlighted,
+///     "<span class=\"token-keyword\">let</span> x <span class=\"token-punctuation\">=</span> \
+///      <span class=\"token-string\">&quot;&lt;&quot;</span><span class=\"token-punctuation\">;</span>"
+/// );
+/// ```
+pub fn highlight_code(code: &str, language: &str) -> String {
+    render(&tokenize(code, language))
+}
+
+/// Renders tokens as HTML.
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens to render
+///
+/// # Returns
+///
+/// A `String` containing the escaped tokens, with highlighted tokens wrapped in classed `<span>`s
+pub fn render(tokens: &[Token]) -> String {
+    let mut highlighted = String::new();
+
+    for token in tokens {
+        render_token(&mut highlighted, token.kind, token.text);
+    }
+
+    highlighted
+}
+
+/// Renders tokens as HTML, one `String` per line of the snippet.
+///
+/// Tokens spanning several lines, such as block comments, are split so that every line is valid
+/// HTML on its own. The newlines themselves are not part of the output.
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens to render
+///
+/// # Returns
+///
+/// A `Vec` containing the rendered HTML of every line
+///
+/// # Example
+///
+/// ```
+/// use zirv_ui::code_block::highlight::{
+///     render_lines,
+///     tokenize,
+/// };
+///
+/// let lines = render_lines(&tokenize("/* a\nb */", "css"));
+/// assert_eq!(
+///     lines,
+///     vec![
+///         "<span class=\"token-comment\">/* a</span>",
+///         "<span class=\"token-comment\">b */</span>"
+///     ]
+/// );
+/// ```
+pub fn render_lines(tokens: &[Token]) -> Vec<String> {
+    let mut lines = vec![String::new()];
+
+    for token in tokens {
+        for (index, part) in token.text.split('\n').enumerate() {
+            if index > 0 {
+                lines.push(String::new());
+            }
+
+            if let Some(line) = lines.last_mut() {
+                render_token(line, token.kind, part);
+            }
+        }
+    }
+
+    lines
+}
+
+/// Appends the HTML of a single token to `out`.
+fn render_token(out: &mut String, kind: TokenKind, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+
+    match kind.class() {
+        Some(class) => {
+            out.push_str("<span class=\"");
+            out.push_str(class);
+            out.push_str("\">");
+            out.push_str(&escape_html(text));
+            out.push_str("</span>");
+        }
+        None => out.push_str(&escape_html(text)),
+    }
+}
+
+/// Escapes the characters of `text` that have a special meaning in HTML.
+///
+/// # Example
+///
+/// ```
+/// use zirv_ui::code_block::highlight::escape_html;
+///
+/// assert_eq!(escape_html("<a href='#'>"), "&lt;a href=&#39;#&#39;&gt;");
+/// ```
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&#39;")
+}
+
+/// Appends a token to `tokens`, skipping empty slices.
+pub(crate) fn push_token<'a>(tokens: &mut Vec<Token<'a>>, kind: TokenKind, text: &'a str) {
+    if !text.is_empty() {
+        tokens.push(Token { kind, text });
+    }
+}
@@ -0,0 +1,247 @@
+//! Code Lines Module
+//!
+//! This module splits a code snippet into highlighted lines for the `CodeBlock` component. It
+//! handles line numbering, the `highlight_lines` range syntax (e.g. `"3-5,9"`) and unified diffs,
+//! whose `+`/`-` markers are turned into line styling instead of being rendered as code.
+
+use std::ops::RangeInclusive;
+
+use super::highlight::{
+    escape_html,
+    render_lines,
+    tokenize,
+};
+
+/// A set of line numbers parsed from a comma separated list of lines and ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LineRanges(Vec<RangeInclusive<usize>>);
+
+impl LineRanges {
+    /// Parses a line specification such as `"3-5,9"`.
+    ///
+    /// Whitespace around entries is ignored, and a range with reversed bounds such as `"5-3"` is
+    /// read as `"3-5"`. Entries that are not a number or a `start-end` range are skipped with a
+    /// warning rather than failing the whole specification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zirv_ui::code_block::lines::LineRanges;
+    ///
+    /// let lines = LineRanges::parse("3-5, 9");
+    /// assert!(lines.contains(4));
+    /// assert!(lines.contains(9));
+    /// assert!(!lines.contains(6));
+    ///
+    /// assert_eq!(LineRanges::parse("5-3"), LineRanges::parse("3-5"));
+    /// ```
+    pub fn parse(spec: &str) -> Self {
+        let ranges = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| {
+                let range = match entry.split_once('-') {
+                    Some((start, end)) => start.trim().parse().ok().zip(end.trim().parse().ok()),
+                    None => entry.parse().ok().map(|line| (line, line)),
+                };
+
+                if range.is_none() {
+                    log::warn!("Ignoring invalid line range {:?}", entry);
+                }
+
+                range.map(|(start, end): (usize, usize)| start.min(end)..=start.max(end))
+            })
+            .collect();
+
+        Self(ranges)
+    }
+
+    /// Checks whether `line` is part of any of the ranges.
+    pub fn contains(&self, line: usize) -> bool {
+        self.0.iter().any(|range| range.contains(&line))
+    }
+}
+
+/// The role of a line within a code block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineKind {
+    /// A line of code, or an unchanged context line of a diff.
+    #[default]
+    Code,
+    /// A line added by a diff (`+`).
+    Added,
+    /// A line removed by a diff (`-`).
+    Removed,
+    /// A diff header such as `@@ -1,4 +1,5 @@` or `--- a/src/lib.rs`.
+    Meta,
+}
+
+/// A highlighted line of a code block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeLine {
+    /// The line number to display, or `None` for lines that are not part of the resulting code
+    /// (removed lines and diff headers).
+    pub number: Option<usize>,
+    /// The role of the line.
+    pub kind: LineKind,
+    /// The highlighted HTML of the line, without any diff marker.
+    pub html: String,
+}
+
+/// Splits a snippet into highlighted, numbered lines.
+///
+/// In diff mode every line is classified by its leading marker, which is removed before
+/// highlighting. Lines before the first hunk header, such as `diff --git` and `index` lines, are
+/// diff headers. Line numbers then follow the resulting code: removed lines are not numbered and a
+/// hunk header `@@ -a,b +c,d @@` restarts numbering at `c`.
+///
+/// # Arguments
+///
+/// * `snippet` - The code snippet, or a unified diff when `diff` is set
+/// * `language` - The programming language of the code
+/// * `start_line` - The number of the first line
+/// * `diff` - Whether the snippet is a unified diff
+///
+/// # Returns
+///
+/// A `Vec` containing one `CodeLine` per line of the snippet
+///
+/// # Example
+///
+/// ```
+/// use zirv_ui::code_block::lines::{
+///     code_lines,
+///     LineKind,
+/// };
+///
+/// let lines = code_lines(" a\n-b\n+c\n", "text", 10, true);
+/// let numbers = lines.iter().map(|line| (line.number, line.kind)).collect::<Vec<_>>();
+/// assert_eq!(
+///     numbers,
+///     vec![(Some(10), LineKind::Code), (None, LineKind::Removed), (Some(11), LineKind::Added)]
+/// );
+///
+/// let diff = "diff --git a/src/main.rs b/src/main.rs
+/// index 3b18e51..a9c2f4d 100644
+/// --- a/src/main.rs
+/// +++ b/src/main.rs
+/// @@ -1,3 +1,3 @@
+///  fn main() {
+/// -    old();
+/// +    new();
+/// ";
+/// let kinds = code_lines(diff, "rust", 1, true).iter().map(|line| line.kind).collect::<Vec<_>>();
+/// assert_eq!(kinds[..5], [LineKind::Meta; 5]);
+/// assert_eq!(kinds[5..], [LineKind::Code, LineKind::Removed, LineKind::Added]);
+/// ```
+pub fn code_lines(snippet: &str, language: &str, start_line: usize, diff: bool) -> Vec<CodeLine> {
+    let classified = classify(snippet, diff);
+    let mut next_number = start_line;
+
+    // Highlight all code lines in one pass so that multi-line tokens keep their styling
+    let code = classified
+        .iter()
+        .filter(|(kind, _)| *kind != LineKind::Meta)
+        .map(|(_, content)| *content)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut highlighted = render_lines(&tokenize(&code, language)).into_iter();
+
+    classified
+        .into_iter()
+        .map(|(kind, content)| {
+            let (number, html) = match kind {
+                LineKind::Code | LineKind::Added => {
+                    next_number += 1;
+                    (Some(next_number - 1), highlighted.next().unwrap_or_default())
+                }
+                LineKind::Removed => (None, highlighted.next().unwrap_or_default()),
+                LineKind::Meta => {
+                    if let Some(start) = hunk_start(content) {
+                        next_number = start;
+                    }
+                    (None, escape_html(content))
+                }
+            };
+
+            CodeLine { number, kind, html }
+        })
+        .collect()
+}
+
+/// Returns the text to place on the clipboard when copying a snippet.
+///
+/// Regular snippets are copied as they are. For diffs the markers are stripped, and removed lines
+/// and diff headers are dropped, so that the clipboard holds the code after the change.
+///
+/// # Example
+///
+/// ```
+/// use zirv_ui::code_block::lines::copy_text;
+///
+/// assert_eq!(copy_text("@@ -1,2 +1,2 @@\n a\n-b\n+c\n", true), "a\nc");
+/// assert_eq!(
+///     copy_text("diff --git a/x b/x\nindex 3b18e51..a9c2f4d 100644\n@@ -1 +1 @@\n-b\n+c\n", true),
+///     "c"
+/// );
+/// ```
+pub fn copy_text(snippet: &str, diff: bool) -> String {
+    if !diff {
+        return snippet.to_string();
+    }
+
+    classify(snippet, true)
+        .into_iter()
+        .filter(|(kind, _)| matches!(kind, LineKind::Code | LineKind::Added))
+        .map(|(_, content)| content)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Classifies every line of a snippet and strips its diff marker.
+///
+/// Diff headers keep their full text. When the diff has hunk headers, every line outside of a hunk
+/// is a header, e.g. the `diff --git` and `index` lines of `git diff`. Outside of diff mode every
+/// line is `LineKind::Code`.
+fn classify(snippet: &str, diff: bool) -> Vec<(LineKind, &str)> {
+    let has_hunks = diff && split_lines(snippet).any(|line| line.starts_with("@@"));
+    let mut in_hunk = false;
+
+    split_lines(snippet)
+        .map(|line| {
+            if !diff {
+                (LineKind::Code, line)
+            } else if line.starts_with("@@") {
+                in_hunk = true;
+                (LineKind::Meta, line)
+            } else if line.starts_with("diff ") {
+                // The header of the next file ends the current hunk
+                in_hunk = false;
+                (LineKind::Meta, line)
+            } else if line.starts_with('\\') {
+                // "\ No newline at end of file"
+                (LineKind::Meta, line)
+            } else if !in_hunk && (has_hunks || line.starts_with("--- ") || line.starts_with("+++ ")) {
+                (LineKind::Meta, line)
+            } else if let Some(content) = line.strip_prefix('+') {
+                (LineKind::Added, content)
+            } else if let Some(content) = line.strip_prefix('-') {
+                (LineKind::Removed, content)
+            } else {
+                (LineKind::Code, line.strip_prefix(' ').unwrap_or(line))
+            }
+        })
+        .collect()
+}
+
+/// Splits a snippet into lines, ignoring the empty line after a trailing newline.
+fn split_lines(snippet: &str) -> impl Iterator<Item = &str> {
+    snippet.strip_suffix('\n').unwrap_or(snippet).split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+/// Parses the start line of the resulting code from a hunk header such as `@@ -1,4 +3,5 @@`.
+fn hunk_start(header: &str) -> Option<usize> {
+    let range = header.split_whitespace().find_map(|part| part.strip_prefix('+'))?;
+    range.split(',').next()?.parse().ok()
+}
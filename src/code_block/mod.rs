@@ -2,6 +2,8 @@
 //!
 //! This module provides a `CodeBlock` component for rendering and interacting with code snippets.
 //! It includes functionality for syntax highlighting and copying code to the clipboard.
+//! Highlighting is provided by the `highlight` module and requires the `highlight_*` feature of the
+//! snippet's language.
 
 use yew::{
     classes,
@@ -14,7 +16,14 @@ use yew::{
 };
 
 pub mod highlight;
+pub mod lines;
 
+use self::lines::{
+    code_lines,
+    copy_text,
+    LineKind,
+    LineRanges,
+};
 use crate::{
     font::FontWeight,
     size::{
@@ -34,24 +43,49 @@ use crate::{
 pub struct CodeBlockProps {
     /// The code snippet to be displayed
     pub snippet: String,
-    /// The programming language of the code snippet (for syntax highlighting)
+    /// The programming language of the code snippet (for syntax highlighting), e.g. `"rust"`
     pub language: String,
+    /// Whether to show line numbers in a gutter next to the code
+    #[prop_or_default]
+    pub line_numbers: bool,
+    /// Lines to highlight, as a comma separated list of lines and ranges (e.g. `"3-5,9"`)
+    #[prop_or_default]
+    pub highlight_lines: Option<String>,
+    /// The number of the first line. Default is `1`.
+    #[prop_or(1)]
+    pub start_line: usize,
+    /// Whether the snippet is a unified diff whose `+`/`-` lines are rendered as added/removed
+    #[prop_or_default]
+    pub diff: bool,
 }
 
 /// CodeBlock Component
 ///
-/// Renders a code block with syntax highlighting and a copy button.
+/// Renders a code block with syntax highlighting and a copy button. Lines can optionally be
+/// numbered and highlighted, and unified diffs are rendered with added/removed styling. Copying a
+/// diff places the resulting code on the clipboard, without the diff markers.
 ///
 /// # Example
 ///
-/// ```
-/// use your_crate::CodeBlock;
+/// ```rust
+/// use yew::{
+///     function_component,
+///     html,
+///     Html,
+/// };
+/// use zirv_ui::CodeBlock;
 ///
-/// html! {
-///     <CodeBlock
-///         snippet="fn main() { println!(\"Hello, World!\"); }"
-///         language="rust"
-///     />
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <CodeBlock
+///             snippet={"fn main() {\n-    println!(\"Hello\");\n+    println!(\"Hello, World!\");\n}"}
+///             language="rust"
+///             line_numbers=true
+///             highlight_lines={"3"}
+///             diff=true
+///         />
+///     }
 /// }
 /// ```
 #[function_component(CodeBlock)]
@@ -59,12 +93,13 @@ pub fn code_block(props: &CodeBlockProps) -> Html {
     // Initialize the toast manager for displaying notifications
     let toasts_manager = use_toast::<Toast>();
 
-    // Apply syntax highlighting to the code snippet
-    let highlighted_html = highlight::highlight_code(&props.snippet, &props.language);
+    // Apply syntax highlighting to the code snippet, line by line
+    let lines = code_lines(&props.snippet, &props.language, props.start_line, props.diff);
+    let highlighted_lines = props.highlight_lines.as_deref().map(LineRanges::parse).unwrap_or_default();
 
     // Callback for the copy button
     let onclick = {
-        let code = props.snippet.clone();
+        let code = copy_text(&props.snippet, props.diff);
         Callback::from(move |_| {
             let code = code.clone();
 
@@ -89,6 +124,41 @@ pub fn code_block(props: &CodeBlockProps) -> Html {
         })
     };
 
+    let lines = lines
+        .into_iter()
+        .map(|line| {
+            let classes = classes!(
+                "code-line",
+                line.number.filter(|number| highlighted_lines.contains(*number)).map(|_| "code-line-highlighted"),
+                match line.kind {
+                    LineKind::Code => None,
+                    LineKind::Added => Some("code-line-added"),
+                    LineKind::Removed => Some("code-line-removed"),
+                    LineKind::Meta => Some("code-line-meta"),
+                }
+            );
+
+            let marker = match line.kind {
+                LineKind::Added => "+",
+                LineKind::Removed => "-",
+                LineKind::Code | LineKind::Meta => " ",
+            };
+
+            html! {
+                <span class={classes}>
+                    if props.line_numbers {
+                        <span class="code-line-number">{line.number.map(|number| number.to_string())}</span>
+                    }
+                    if props.diff {
+                        <span class="code-line-marker">{marker}</span>
+                    }
+                    // Render the highlighted code as HTML
+                    <span class="code-line-content">{Html::from_html_unchecked(AttrValue::from(line.html))}</span>
+                </span>
+            }
+        })
+        .collect::<Html>();
+
     // Render the CodeBlock component
     html! {
         <div class="code-block">
@@ -98,9 +168,8 @@ pub fn code_block(props: &CodeBlockProps) -> Html {
             </div>
             <div class="code-block_snippet">
                 <pre>
-                    <code>
-                        // Render the highlighted code as HTML
-                        {Html::from_html_unchecked(AttrValue::from(highlighted_html))}
+                    <code class={format!("language-{}", props.language.to_ascii_lowercase())}>
+                        {lines}
                     </code>
                 </pre>
             </div>
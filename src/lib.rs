@@ -55,10 +55,12 @@ pub mod list;
 pub mod loader;
 pub mod menu;
 pub mod options;
+pub mod pagination;
 pub mod table;
 pub mod text;
 pub mod theme;
 pub mod toast;
+pub mod virtualize;
 
 #[cfg(feature = "button")]
 pub use button::{
@@ -118,8 +120,15 @@ pub use menu::{
     use_menu::use_menu,
 };
 pub use options::*;
+#[cfg(feature = "pagination")]
+pub use pagination::{
+    state::PaginationState,
+    Pagination,
+    PaginationProps,
+};
 #[cfg(feature = "table")]
 pub use table::{
+    column::Column,
     Table,
     TableProps,
 };
@@ -140,6 +149,16 @@ pub use text::{
 };
 #[cfg(feature = "theme")]
 pub use theme::{
+    builder::{
+        ThemeBuilder,
+        ThemeError,
+        ThemeField,
+    },
+    mode::ThemeMode,
+    use_theme::{
+        use_theme,
+        ThemeState,
+    },
     Theme,
     ThemeProps,
     ThemeProvider,
@@ -157,6 +176,12 @@ pub use toast::{
         ToastProviderProps,
     },
     use_toast::use_toast,
-    utils::ToastType,
+    utils::{
+        Politeness,
+        ToastOrder,
+        ToastPosition,
+        ToastType,
+    },
     Toast,
+    ToastAction,
 };
@@ -2,11 +2,23 @@ use yew::{
     classes,
     function_component,
     html,
+    use_node_ref,
     Classes,
     Html,
     Properties,
 };
 
+use crate::{
+    options::{
+        overflow::Overflow,
+        size::Height,
+    },
+    virtualize::{
+        use_virtual_window::use_virtual_window,
+        RowHeight,
+    },
+};
+
 #[derive(Clone, PartialEq, Default)]
 pub enum ListStyle {
     Ordered,
@@ -21,33 +33,64 @@ pub struct ListProps {
     pub style: Option<ListStyle>,
     #[prop_or(None)]
     pub classes: Option<Classes>,
+    /// Renders only the items scrolled into view, with items of this height. The list is placed
+    /// in a scroll container sized by `height`.
+    #[prop_or_default]
+    pub virtualize: Option<RowHeight>,
+    /// The number of extra items rendered above and below the visible items of a virtualized list.
+    #[prop_or(5)]
+    pub overscan: usize,
+    /// The height of the scroll container of a virtualized list. Default is `Height::Medium`.
+    #[prop_or(Height::Medium)]
+    pub height: Height,
 }
 
 #[function_component(List)]
 pub fn list<T>(props: &ListProps) -> Html {
-    match &props.style {
-        Some(style) => match style {
-            ListStyle::Ordered => {
-                html! {
-                    <ol class={classes!(&props.classes)}>
-                        {props.data.iter().map(|item| html! { <li>{item}</li> }).collect::<Vec<_>>()}
-                    </ol>
-                }
-            }
-            ListStyle::Unordered => {
-                html! {
-                    <ul class={classes!(&props.classes)}>
-                        {props.data.iter().map(|item| html! { <li>{item}</li> }).collect::<Vec<_>>()}
-                    </ul>
-                }
+    let scroll_container = use_node_ref();
+    let list = use_node_ref();
+    let window =
+        use_virtual_window(scroll_container.clone(), list.clone(), props.data.len(), props.virtualize, props.overscan);
+
+    let spacer = |height: f64| {
+        (height > 0.0)
+            .then(|| html! { <li class="list-spacer" aria-hidden="true" style={format!("height: {}px", height)} /> })
+    };
+    let items = html! {
+        <>
+            {spacer(window.before)}
+            {props.data[window.range()].iter().map(|item| html! { <li>{item}</li> }).collect::<Vec<_>>()}
+            {spacer(window.after)}
+        </>
+    };
+
+    let list = match props.style {
+        Some(ListStyle::Ordered) => {
+            // Keep the numbering of the rendered items in line with their position in `data`. The
+            // spacer in front of them is an item as well, so it takes the number before the first.
+            let start = if window.before > 0.0 { window.start } else { window.start + 1 };
+
+            html! {
+                <ol ref={list} class={classes!(&props.classes)} start={start.to_string()}>
+                    {items}
+                </ol>
             }
-        },
-        None => {
+        }
+        _ => {
             html! {
-                <ul class={classes!(&props.classes)}>
-                    {props.data.iter().map(|item| html! { <li>{item}</li> }).collect::<Vec<_>>()}
+                <ul ref={list} class={classes!(&props.classes)}>
+                    {items}
                 </ul>
             }
         }
+    };
+
+    match props.virtualize {
+        Some(_) => html! {
+            <div ref={scroll_container} class={classes!("list-scroll", &props.height, &Overflow::Auto)}>
+                {list}
+            </div>
+        },
+        None => list,
     }
 }
@@ -2,7 +2,8 @@
 //!
 //! This module defines the `BackgroundColor` enum, which represents various background color
 //! options. It also provides an implementation to convert `BackgroundColor` into Yew's `Classes`
-//! for CSS styling.
+//! for CSS styling. Every palette color of the theme additionally has a scale of shades from `50`
+//! (lightest) to `900` (darkest), selected with a `Palette` and a `Shade`.
 //!
 //! # Example
 //!
@@ -32,6 +33,146 @@ use yew::{
     Classes,
 };
 
+use super::font::TextColor;
+
+/// Enum representing the palette colors of a theme that have a scale of shades.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    /// The primary color.
+    Primary,
+    /// The secondary color.
+    Secondary,
+    /// The tertiary color.
+    Tertiary,
+    /// The container (module) color.
+    Container,
+    /// The background color.
+    Background,
+    /// The success color.
+    Success,
+    /// The warning color.
+    Warning,
+    /// The error color.
+    Error,
+    /// The disabled color.
+    Disabled,
+}
+
+impl Palette {
+    /// Every palette color, in the order the theme defines them.
+    pub const ALL: [Palette; 9] = [
+        Palette::Background,
+        Palette::Container,
+        Palette::Primary,
+        Palette::Secondary,
+        Palette::Tertiary,
+        Palette::Success,
+        Palette::Warning,
+        Palette::Error,
+        Palette::Disabled,
+    ];
+
+    /// Returns the name of the palette color used in class names, e.g. `"primary"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Palette::Primary => "primary",
+            Palette::Secondary => "secondary",
+            Palette::Tertiary => "tertiary",
+            Palette::Container => "container",
+            Palette::Background => "background",
+            Palette::Success => "success",
+            Palette::Warning => "warning",
+            Palette::Error => "error",
+            Palette::Disabled => "disabled",
+        }
+    }
+
+    /// Returns the CSS variable holding the palette color, e.g. `"--color-primary"`.
+    ///
+    /// The variable of every shade appends the shade, e.g. `"--color-primary-500"`.
+    pub fn css_variable(&self) -> &'static str {
+        match self {
+            Palette::Primary => "--color-primary",
+            Palette::Secondary => "--color-secondary",
+            Palette::Tertiary => "--color-tertiery",
+            Palette::Container => "--module-color",
+            Palette::Background => "--background-color",
+            Palette::Success => "--color-success",
+            Palette::Warning => "--color-warning",
+            Palette::Error => "--color-error",
+            Palette::Disabled => "--color-disabled",
+        }
+    }
+}
+
+/// Enum representing a shade of a palette color, from the lightest (`S50`) to the darkest
+/// (`S900`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Shade {
+    S50,
+    S100,
+    S200,
+    S300,
+    S400,
+    S500,
+    S600,
+    S700,
+    S800,
+    S900,
+}
+
+impl Shade {
+    /// Every shade, from the lightest to the darkest.
+    pub const ALL: [Shade; 10] = [
+        Shade::S50,
+        Shade::S100,
+        Shade::S200,
+        Shade::S300,
+        Shade::S400,
+        Shade::S500,
+        Shade::S600,
+        Shade::S700,
+        Shade::S800,
+        Shade::S900,
+    ];
+
+    /// Returns the number of the shade, e.g. `500`.
+    pub fn value(&self) -> u16 {
+        match self {
+            Shade::S50 => 50,
+            Shade::S100 => 100,
+            Shade::S200 => 200,
+            Shade::S300 => 300,
+            Shade::S400 => 400,
+            Shade::S500 => 500,
+            Shade::S600 => 600,
+            Shade::S700 => 700,
+            Shade::S800 => 800,
+            Shade::S900 => 900,
+        }
+    }
+
+    /// Returns the next darker shade, or `S900` itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::options::color::Shade;
+    ///
+    /// assert_eq!(Shade::S500.darker(), Shade::S600);
+    /// assert_eq!(Shade::S900.darker(), Shade::S900);
+    /// assert_eq!(Shade::S50.lighter(), Shade::S50);
+    /// ```
+    pub fn darker(&self) -> Self {
+        Self::ALL[(*self as usize + 1).min(Self::ALL.len() - 1)]
+    }
+
+    /// Returns the next lighter shade, or `S50` itself.
+    pub fn lighter(&self) -> Self {
+        Self::ALL[(*self as usize).saturating_sub(1)]
+    }
+}
+
 /// Enum representing the background color options.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BackgroundColor {
@@ -98,6 +239,46 @@ pub enum BackgroundColor {
     ErrorDark,
     /// Light variant of the error background color.
     ErrorLight,
+    /// A shade of a palette color, rendered as `background-color-{palette}-{shade}`.
+    ///
+    /// The names match `$palette-variables` and `$shades` in `style/abstract/_variables.scss`, and
+    /// every class exists in the compiled stylesheet:
+    ///
+    /// ```rust
+    /// use yew::Classes;
+    /// use zirv_ui::options::color::{
+    ///     BackgroundColor,
+    ///     Palette,
+    ///     Shade,
+    /// };
+    ///
+    /// let stylesheet = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/style/dist/main.css"));
+    /// let palettes = [
+    ///     "background",
+    ///     "container",
+    ///     "primary",
+    ///     "secondary",
+    ///     "tertiary",
+    ///     "success",
+    ///     "warning",
+    ///     "error",
+    ///     "disabled",
+    /// ];
+    /// let shades = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+    /// assert_eq!(Palette::ALL.len(), palettes.len());
+    /// assert_eq!(Shade::ALL.len(), shades.len());
+    ///
+    /// for (palette, name) in Palette::ALL.into_iter().zip(palettes) {
+    ///     for (shade, value) in Shade::ALL.into_iter().zip(shades) {
+    ///         let class: Classes = (&BackgroundColor::Shade(palette, shade)).into();
+    ///         let expected = format!("background-color-{}-{}", name, value);
+    ///
+    ///         assert_eq!(class.to_string(), expected);
+    ///         assert!(stylesheet.contains(&format!(".{} {{", expected)));
+    ///     }
+    /// }
+    /// ```
+    Shade(Palette, Shade),
 }
 
 impl From<&BackgroundColor> for Classes {
@@ -144,6 +325,9 @@ impl From<&BackgroundColor> for Classes {
             BackgroundColor::Error => classes!("background-color-error"),
             BackgroundColor::ErrorDark => classes!("background-color-error-dark"),
             BackgroundColor::ErrorLight => classes!("background-color-error-light"),
+            BackgroundColor::Shade(palette, shade) => {
+                classes!(format!("background-color-{}-{}", palette.name(), shade.value()))
+            }
         }
     }
 }
@@ -162,6 +346,7 @@ impl BackgroundColor {
             Self::Success | Self::SuccessDark | Self::SuccessLight => Self::SuccessDark,
             Self::Warning | Self::WarningDark | Self::WarningLight => Self::WarningDark,
             Self::Error | Self::ErrorDark | Self::ErrorLight => Self::ErrorDark,
+            Self::Shade(palette, shade) => Self::Shade(*palette, shade.darker()),
         }
     }
 
@@ -178,6 +363,32 @@ impl BackgroundColor {
             Self::Success | Self::SuccessDark | Self::SuccessLight => Self::SuccessLight,
             Self::Warning | Self::WarningDark | Self::WarningLight => Self::WarningLight,
             Self::Error | Self::ErrorDark | Self::ErrorLight => Self::ErrorLight,
+            Self::Shade(palette, shade) => Self::Shade(*palette, shade.lighter()),
+        }
+    }
+
+    /// Returns the text color shown on this background by default.
+    ///
+    /// The primary and secondary colors have a text color derived from them by contrast, see
+    /// `Theme::on_primary_color`. Other backgrounds use the primary text color of the theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::options::{
+    ///     color::BackgroundColor,
+    ///     font::TextColor,
+    /// };
+    ///
+    /// assert_eq!(BackgroundColor::Primary.on_color(), TextColor::OnPrimary);
+    /// assert_eq!(BackgroundColor::SecondaryDark.on_color(), TextColor::OnSecondary);
+    /// assert_eq!(BackgroundColor::Error.on_color(), TextColor::TextPrimary);
+    /// ```
+    pub fn on_color(&self) -> TextColor {
+        match self {
+            Self::Primary | Self::PrimaryDark | Self::PrimaryLight => TextColor::OnPrimary,
+            Self::Secondary | Self::SecondaryDark | Self::SecondaryLight => TextColor::OnSecondary,
+            _ => TextColor::TextPrimary,
         }
     }
 
@@ -214,6 +425,7 @@ impl BackgroundColor {
             Self::Error => classes!("background-color-error"),
             Self::ErrorDark => classes!("background-color-error-dark"),
             Self::ErrorLight => classes!("background-color-error-light"),
+            Self::Shade(..) => self.into(),
         }
     }
 }
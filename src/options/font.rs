@@ -40,6 +40,11 @@ use yew::{
     Classes,
 };
 
+use super::color::{
+    Palette,
+    Shade,
+};
+
 /// Enum representing the font size options.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum FontSize {
@@ -392,12 +397,58 @@ pub enum TextColor {
     TextPrimary,
     /// Secondary text color.
     TextSecondary,
+    /// Text color shown on the primary color, derived from it by contrast.
+    OnPrimary,
+    /// Text color shown on the secondary color, derived from it by contrast.
+    OnSecondary,
     /// Success text color.
     Success,
     /// Warning text color.
     Warning,
     /// Error text color.
     Error,
+    /// A shade of a palette color, rendered as `text-color-{palette}-{shade}`.
+    ///
+    /// The names match `$palette-variables` and `$shades` in `style/abstract/_variables.scss`, and
+    /// every class exists in the compiled stylesheet:
+    ///
+    /// ```rust
+    /// use yew::Classes;
+    /// use zirv_ui::options::{
+    ///     color::{
+    ///         Palette,
+    ///         Shade,
+    ///     },
+    ///     font::TextColor,
+    /// };
+    ///
+    /// let stylesheet = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/style/dist/main.css"));
+    /// let palettes = [
+    ///     "background",
+    ///     "container",
+    ///     "primary",
+    ///     "secondary",
+    ///     "tertiary",
+    ///     "success",
+    ///     "warning",
+    ///     "error",
+    ///     "disabled",
+    /// ];
+    /// let shades = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+    /// assert_eq!(Palette::ALL.len(), palettes.len());
+    /// assert_eq!(Shade::ALL.len(), shades.len());
+    ///
+    /// for (palette, name) in Palette::ALL.into_iter().zip(palettes) {
+    ///     for (shade, value) in Shade::ALL.into_iter().zip(shades) {
+    ///         let class: Classes = (&TextColor::Shade(palette, shade)).into();
+    ///         let expected = format!("text-color-{}-{}", name, value);
+    ///
+    ///         assert_eq!(class.to_string(), expected);
+    ///         assert!(stylesheet.contains(&format!(".{} {{", expected)));
+    ///     }
+    /// }
+    /// ```
+    Shade(Palette, Shade),
 }
 
 impl From<&TextColor> for Classes {
@@ -421,9 +472,12 @@ impl From<&TextColor> for Classes {
             TextColor::Background => classes!("text-color-background"),
             TextColor::TextPrimary => classes!("text-color-text-primary"),
             TextColor::TextSecondary => classes!("text-color-text-secondary"),
+            TextColor::OnPrimary => classes!("text-color-on-primary"),
+            TextColor::OnSecondary => classes!("text-color-on-secondary"),
             TextColor::Success => classes!("text-color-success"),
             TextColor::Warning => classes!("text-color-warning"),
             TextColor::Error => classes!("text-color-error"),
+            TextColor::Shade(palette, shade) => classes!(format!("text-color-{}-{}", palette.name(), shade.value())),
         }
     }
 }
@@ -0,0 +1,179 @@
+//! Pagination Component
+//!
+//! This module provides a reusable pagination control for the Yew framework. The `Pagination`
+//! component renders a "showing X–Y of Z" summary, previous and next buttons, buttons for the
+//! pages around the current one and a page-size selector. It is controlled: the current
+//! `PaginationState` is passed in and every change is emitted through `on_change`, so the same
+//! component works for data paged in the browser and for data paged by a server.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::{
+//!     function_component,
+//!     html,
+//!     use_state,
+//!     Callback,
+//!     Html,
+//! };
+//! use zirv_ui::{
+//!     pagination::state::PaginationState,
+//!     Pagination,
+//! };
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     let state = use_state(|| PaginationState::new(25, 1234));
+//!     let on_change = {
+//!         let state = state.clone();
+//!         Callback::from(move |new_state| state.set(new_state))
+//!     };
+//!
+//!     html! {
+//!         <Pagination state={*state} {on_change} />
+//!     }
+//! }
+//! ```
+
+pub mod state;
+
+use web_sys::HtmlSelectElement;
+use yew::{
+    classes,
+    function_component,
+    html,
+    Callback,
+    Classes,
+    Event,
+    Html,
+    Properties,
+    TargetCast,
+};
+
+use self::state::{
+    PaginationState,
+    PAGE_SIZES,
+};
+
+/// Where the items of a paginated view are sliced into pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaginationMode {
+    /// All items are given and the current page is sliced out of them.
+    #[default]
+    Client,
+    /// Only the items of the current page are given, and the total comes from the
+    /// `PaginationState`. Page changes are emitted so the page can be requested from the server.
+    Server,
+}
+
+/// Returns the page sizes offered by default.
+pub(crate) fn default_page_sizes() -> Vec<usize> {
+    PAGE_SIZES.to_vec()
+}
+
+/// Properties for the `Pagination` component.
+#[derive(Clone, PartialEq, Properties)]
+pub struct PaginationProps {
+    /// The current pagination state.
+    pub state: PaginationState,
+    /// Callback for when the page or the page size changes.
+    pub on_change: Callback<PaginationState>,
+    /// The page sizes offered by the page-size selector. The selector is hidden when empty.
+    #[prop_or_else(default_page_sizes)]
+    pub page_sizes: Vec<usize>,
+    /// Whether to show the "showing X–Y of Z" summary. Default is `true`.
+    #[prop_or(true)]
+    pub show_summary: bool,
+    /// The number of page buttons shown on each side of the current page. Default is `1`.
+    #[prop_or(1)]
+    pub siblings: usize,
+    /// Additional classes for the pagination.
+    #[prop_or_default]
+    pub classes: Option<Classes>,
+}
+
+/// The `Pagination` component.
+///
+/// # Properties
+///
+/// - `state`: The current pagination state.
+/// - `on_change`: Callback for when the page or the page size changes.
+/// - `page_sizes`: The page sizes offered by the page-size selector. Default is `[10, 25, 50,
+///   100]`.
+/// - `show_summary`: Whether to show the "showing X–Y of Z" summary. Default is `true`.
+/// - `siblings`: The number of page buttons shown on each side of the current page. Default is `1`.
+/// - `classes`: Additional classes for the pagination.
+#[function_component(Pagination)]
+pub fn pagination(props: &PaginationProps) -> Html {
+    let state = props.state.with_page(props.state.page);
+
+    let go_to = |page: usize| {
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| on_change.emit(state.with_page(page)))
+    };
+
+    let pages = state
+        .visible_pages(props.siblings)
+        .into_iter()
+        .map(|page| match page {
+            Some(page) => {
+                let current = page == state.page;
+
+                html! {
+                    <button
+                        class={classes!("pagination-button", current.then_some("pagination-button-active"))}
+                        aria-current={current.then_some("page")}
+                        onclick={go_to(page)}
+                    >
+                        {page + 1}
+                    </button>
+                }
+            }
+            None => html! { <span class="pagination-ellipsis" aria-hidden="true">{"…"}</span> },
+        })
+        .collect::<Html>();
+
+    let on_page_size = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |event: Event| {
+            let select = event.target_unchecked_into::<HtmlSelectElement>();
+            if let Ok(page_size) = select.value().parse() {
+                on_change.emit(state.with_page_size(page_size));
+            }
+        })
+    };
+
+    html! {
+        <nav class={classes!("pagination", &props.classes)} aria-label="Pagination">
+            if props.show_summary {
+                <span class="pagination-summary" aria-live="polite">{state.summary()}</span>
+            }
+            <div class="pagination-pages">
+                <button
+                    class="pagination-button"
+                    aria-label="Previous page"
+                    disabled={!state.has_previous()}
+                    onclick={go_to(state.page.saturating_sub(1))}
+                >
+                    {"‹"}
+                </button>
+                {pages}
+                <button
+                    class="pagination-button"
+                    aria-label="Next page"
+                    disabled={!state.has_next()}
+                    onclick={go_to(state.page + 1)}
+                >
+                    {"›"}
+                </button>
+            </div>
+            if !props.page_sizes.is_empty() {
+                <select class="pagination-size" aria-label="Rows per page" onchange={on_page_size}>
+                    {for props.page_sizes.iter().map(|size| html! {
+                        <option value={size.to_string()} selected={*size == state.page_size}>{size}</option>
+                    })}
+                </select>
+            }
+        </nav>
+    }
+}
@@ -0,0 +1,154 @@
+//! Pagination State Module
+//!
+//! This module provides the `PaginationState` model shared by the `Pagination` component and the
+//! `Table`: the page size, the current page and the total number of items. It only does
+//! arithmetic, so it can be used and tested without a browser.
+
+use std::ops::Range;
+
+/// The page sizes offered by the page-size selector by default.
+pub const PAGE_SIZES: [usize; 4] = [10, 25, 50, 100];
+
+/// The state of a paginated collection.
+///
+/// Pages are numbered from `0`. The current page is always clamped to the existing pages, so a
+/// state stays valid when the number of items shrinks.
+///
+/// # Properties
+/// - `page`: The index of the current page.
+/// - `page_size`: The number of items per page, at least `1`.
+/// - `total`: The total number of items across all pages.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::pagination::state::PaginationState;
+///
+/// let state = PaginationState::new(10, 57).with_page(5);
+/// assert_eq!(state.page_count(), 6);
+/// assert_eq!(state.range(), 50..57);
+/// assert_eq!(state.summary(), "Showing 51–57 of 57");
+///
+/// // Changing the page size keeps the first visible item on screen
+/// let state = state.with_page_size(25);
+/// assert_eq!(state.page, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginationState {
+    pub page: usize,
+    pub page_size: usize,
+    pub total: usize,
+}
+
+impl Default for PaginationState {
+    fn default() -> Self {
+        Self::new(PAGE_SIZES[0], 0)
+    }
+}
+
+impl PaginationState {
+    /// Creates a new pagination state showing the first page.
+    ///
+    /// # Parameters
+    /// - `page_size`: The number of items per page. A size of `0` is treated as `1`.
+    /// - `total`: The total number of items.
+    pub fn new(page_size: usize, total: usize) -> Self {
+        Self { page: 0, page_size: page_size.max(1), total }
+    }
+
+    /// Moves to another page, clamped to the existing pages.
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page.min(self.page_count() - 1);
+        self
+    }
+
+    /// Changes the page size, moving to the page that contains the first item of the current page.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        let first = self.range().start;
+        self.page_size = page_size.max(1);
+        self.with_page(first / self.page_size)
+    }
+
+    /// Changes the total number of items, clamping the current page.
+    pub fn with_total(mut self, total: usize) -> Self {
+        self.total = total;
+        self.with_page(self.page)
+    }
+
+    /// Returns the number of pages. An empty collection still has one (empty) page.
+    pub fn page_count(&self) -> usize {
+        self.total.div_ceil(self.page_size.max(1)).max(1)
+    }
+
+    /// Checks whether there is a page before the current one.
+    pub fn has_previous(&self) -> bool {
+        self.page > 0
+    }
+
+    /// Checks whether there is a page after the current one.
+    pub fn has_next(&self) -> bool {
+        self.page + 1 < self.page_count()
+    }
+
+    /// Returns the range of item indices on the current page.
+    pub fn range(&self) -> Range<usize> {
+        let start = (self.page.min(self.page_count() - 1) * self.page_size).min(self.total);
+        start..(start + self.page_size).min(self.total)
+    }
+
+    /// Returns the "showing X–Y of Z" summary of the current page, counting items from `1`.
+    pub fn summary(&self) -> String {
+        let range = self.range();
+
+        if range.is_empty() {
+            format!("Showing 0 of {}", self.total)
+        } else {
+            format!("Showing {}–{} of {}", range.start + 1, range.end, self.total)
+        }
+    }
+
+    /// Returns the pages to offer as buttons.
+    ///
+    /// The first, the last and the pages around the current one are always included. Longer gaps
+    /// are collapsed into a `None`, which is rendered as an ellipsis.
+    ///
+    /// # Parameters
+    /// - `siblings`: The number of pages shown on each side of the current page.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::pagination::state::PaginationState;
+    ///
+    /// let state = PaginationState::new(10, 200).with_page(9);
+    /// assert_eq!(
+    ///     state.visible_pages(1),
+    ///     vec![Some(0), None, Some(8), Some(9), Some(10), None, Some(19)]
+    /// );
+    ///
+    /// let state = PaginationState::new(10, 200).with_page(2);
+    /// assert_eq!(state.visible_pages(1), vec![Some(0), Some(1), Some(2), Some(3), None, Some(19)]);
+    /// ```
+    pub fn visible_pages(&self, siblings: usize) -> Vec<Option<usize>> {
+        let last = self.page_count() - 1;
+        let start = self.page.saturating_sub(siblings);
+        let end = (self.page + siblings).min(last);
+
+        let mut pages = Vec::new();
+        let mut previous = None;
+        for page in std::iter::once(0).chain(start..=end).chain(std::iter::once(last)) {
+            match previous {
+                Some(previous) if page <= previous => continue,
+                // A gap of a single page shows that page rather than an ellipsis
+                Some(previous) if page == previous + 2 => pages.push(Some(previous + 1)),
+                Some(previous) if page > previous + 2 => pages.push(None),
+                _ => {}
+            }
+
+            pages.push(Some(page));
+            previous = Some(page);
+        }
+
+        pages
+    }
+}
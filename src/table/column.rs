@@ -0,0 +1,263 @@
+//! Table Column Module
+//!
+//! This module provides the `Column` type describing one column of a `Table`. A column knows how
+//! to turn a row of the table's data type into a text value, and optionally how to render that row
+//! as arbitrary `Html` and how to order two rows, so domain types can be passed to the table
+//! without stringifying them first.
+
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    rc::Rc,
+};
+
+use yew::{
+    html,
+    Html,
+};
+
+use super::{
+    filter::FilterKind,
+    group::Aggregate,
+    sort::{
+        SortKind,
+        SortValue,
+    },
+};
+
+/// Extracts the text value of a row.
+pub type Accessor<T> = Rc<dyn Fn(&T) -> String>;
+/// Renders the cell of a row as `Html`.
+pub type CellRenderer<T> = Rc<dyn Fn(&T) -> Html>;
+/// Orders two rows in ascending order.
+pub type Comparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// A column of a `Table` displaying rows of type `T`.
+///
+/// # Properties
+/// - `header`: The text shown in the header cell of the column.
+/// - `accessor`: Extracts the text value of a row, used for display and sorting by default.
+/// - `renderer`: Renders the cell of a row as `Html`, replacing the text value.
+/// - `sort_kind`: How the text values are compared when the table is sorted by this column.
+/// - `comparator`: Orders two rows when the table is sorted by this column, replacing `sort_kind`.
+/// - `filter`: The filter input offered for this column.
+/// - `width`: The initial width of the column in pixels.
+/// - `aggregate`: How the column is summarized in the header of a row group.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::html;
+/// use zirv_ui::table::column::Column;
+///
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let columns = vec![
+///     Column::new("Name", |user: &User| user.name.clone())
+///         .with_renderer(|user: &User| html! { <strong>{&user.name}</strong> }),
+///     Column::new("Age", |user: &User| user.age.to_string())
+///         .with_comparator(|a: &User, b: &User| a.age.cmp(&b.age)),
+/// ];
+///
+/// let alice = User { name: "Alice".into(), age: 9 };
+/// let bob = User { name: "Bob".into(), age: 10 };
+/// assert_eq!(columns[0].value(&alice), "Alice");
+/// assert!(columns[1].compare(&alice, &bob).is_lt());
+/// ```
+pub struct Column<T> {
+    pub(crate) header: String,
+    pub(crate) accessor: Accessor<T>,
+    pub(crate) renderer: Option<CellRenderer<T>>,
+    pub(crate) sort_kind: SortKind,
+    pub(crate) comparator: Option<Comparator<T>>,
+    pub(crate) filter: FilterKind,
+    pub(crate) width: Option<f64>,
+    pub(crate) aggregate: Aggregate,
+}
+
+impl<T> Column<T> {
+    /// Creates a new column.
+    ///
+    /// # Parameters
+    /// - `header`: The text shown in the header cell of the column.
+    /// - `accessor`: Extracts the text value of a row.
+    ///
+    /// # Returns
+    /// - A new `Column` rendering the text value of every row and sorting by it.
+    pub fn new(header: impl Into<String>, accessor: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            header: header.into(),
+            accessor: Rc::new(accessor),
+            renderer: None,
+            sort_kind: SortKind::default(),
+            comparator: None,
+            filter: FilterKind::default(),
+            width: None,
+            aggregate: Aggregate::default(),
+        }
+    }
+
+    /// Renders the cells of this column with a custom renderer instead of the text value.
+    ///
+    /// # Parameters
+    /// - `renderer`: Renders the cell of a row, e.g. as a button, badge or link.
+    pub fn with_renderer(mut self, renderer: impl Fn(&T) -> Html + 'static) -> Self {
+        self.renderer = Some(Rc::new(renderer));
+        self
+    }
+
+    /// Sets how the text values of this column are compared when sorting.
+    ///
+    /// # Parameters
+    /// - `sort_kind`: The kind of values in the column, e.g. `SortKind::Numeric`.
+    pub fn with_sort_kind(mut self, sort_kind: SortKind) -> Self {
+        self.sort_kind = sort_kind;
+        self
+    }
+
+    /// Sorts this column with a custom comparator instead of comparing the text values.
+    ///
+    /// # Parameters
+    /// - `comparator`: Orders two rows in ascending order.
+    pub fn with_comparator(mut self, comparator: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.comparator = Some(Rc::new(comparator));
+        self
+    }
+
+    /// Offers a filter input for this column in the second header row of the table.
+    ///
+    /// # Parameters
+    /// - `filter`: The kind of filter input, e.g. `FilterKind::Text`.
+    pub fn with_filter(mut self, filter: FilterKind) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets the initial width of this column, e.g. a width persisted from `on_column_resize`.
+    ///
+    /// # Parameters
+    /// - `width`: The width in pixels.
+    pub fn with_width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Summarizes this column in the header of every row group when the table is grouped.
+    ///
+    /// # Parameters
+    /// - `aggregate`: The summary, e.g. `Aggregate::Sum`.
+    pub fn with_aggregate(mut self, aggregate: Aggregate) -> Self {
+        self.aggregate = aggregate;
+        self
+    }
+
+    /// Returns the header text of the column.
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Returns the text value of a row in this column.
+    pub fn value(&self, row: &T) -> String {
+        (self.accessor)(row)
+    }
+
+    /// Renders the cell of a row in this column.
+    pub fn render(&self, row: &T) -> Html {
+        match &self.renderer {
+            Some(renderer) => renderer(row),
+            None => html! { {self.value(row)} },
+        }
+    }
+
+    /// Orders two rows by this column in ascending order.
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        match &self.comparator {
+            Some(comparator) => comparator(a, b),
+            None => self.sort_kind.compare(&self.value(a), &self.value(b)),
+        }
+    }
+
+    /// Prepares the value of a row for sorting, or `None` when the column sorts with a comparator.
+    pub(crate) fn sort_value(&self, row: &T) -> Option<SortValue> {
+        match &self.comparator {
+            Some(_) => None,
+            None => Some(self.sort_kind.sort_value(self.value(row))),
+        }
+    }
+}
+
+impl Column<Vec<String>> {
+    /// Creates a column showing the cell at `index` of rows made of strings.
+    ///
+    /// Rows that are too short to have a cell at `index` show an empty cell, so ragged rows can
+    /// be displayed and sorted safely.
+    ///
+    /// # Parameters
+    /// - `header`: The text shown in the header cell of the column.
+    /// - `index`: The index of the cell within a row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::table::column::Column;
+    ///
+    /// let column = Column::index("Email", 2);
+    /// assert_eq!(column.value(&vec!["Alice".to_string()]), "");
+    /// ```
+    pub fn index(header: impl Into<String>, index: usize) -> Self {
+        Self::new(header, move |row: &Vec<String>| row.get(index).cloned().unwrap_or_default())
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            accessor: self.accessor.clone(),
+            renderer: self.renderer.clone(),
+            sort_kind: self.sort_kind,
+            comparator: self.comparator.clone(),
+            filter: self.filter,
+            width: self.width,
+            aggregate: self.aggregate,
+        }
+    }
+}
+
+impl<T> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        fn same<F: ?Sized>(a: &Option<Rc<F>>, b: &Option<Rc<F>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        self.header == other.header
+            && Rc::ptr_eq(&self.accessor, &other.accessor)
+            && same(&self.renderer, &other.renderer)
+            && self.sort_kind == other.sort_kind
+            && same(&self.comparator, &other.comparator)
+            && self.filter == other.filter
+            && self.width == other.width
+            && self.aggregate == other.aggregate
+    }
+}
+
+impl<T> Debug for Column<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Column")
+            .field("header", &self.header)
+            .field("renderer", &self.renderer.is_some())
+            .field("sort_kind", &self.sort_kind)
+            .field("comparator", &self.comparator.is_some())
+            .field("filter", &self.filter)
+            .field("width", &self.width)
+            .field("aggregate", &self.aggregate)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,218 @@
+//! Table Export Module
+//!
+//! This module provides the export of `Table` data to CSV and JSON. Serializing is done by pure
+//! functions working on rows and columns, so the output can be tested without a browser, while
+//! `download` hands the serialized text to the browser as a file.
+
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::{
+    JsCast,
+    JsValue,
+};
+use web_sys::{
+    Blob,
+    BlobPropertyBag,
+    HtmlAnchorElement,
+    Url,
+};
+
+use super::column::Column;
+
+/// The file formats table data can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values as described by RFC 4180, with a header row.
+    Csv,
+    /// An array with an object per row, mapping the column headers to the text values.
+    Json,
+}
+
+impl ExportFormat {
+    /// Returns the label of the format, as shown on its export button.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+        }
+    }
+
+    /// Returns the file extension of the format, without the leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// Returns the MIME type of the format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv;charset=utf-8",
+            ExportFormat::Json => "application/json;charset=utf-8",
+        }
+    }
+
+    /// Serializes rows in this format.
+    ///
+    /// # Parameters
+    /// - `rows`: The rows to serialize, in order.
+    /// - `columns`: The columns to serialize for every row.
+    ///
+    /// # Returns
+    /// - The serialized rows.
+    pub fn serialize<T>(&self, rows: &[&T], columns: &[Column<T>]) -> String {
+        match self {
+            ExportFormat::Csv => to_csv(rows, columns),
+            ExportFormat::Json => to_json(rows, columns),
+        }
+    }
+}
+
+/// Serializes rows to CSV following RFC 4180.
+///
+/// The first record holds the column headers. Fields containing a comma, a double quote or a line
+/// break are enclosed in double quotes, with double quotes inside them doubled, and every record
+/// ends with a CRLF line break.
+///
+/// # Parameters
+/// - `rows`: The rows to serialize, in order.
+/// - `columns`: The columns to serialize for every row.
+///
+/// # Returns
+/// - The CSV text.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::{
+///     column::Column,
+///     export::to_csv,
+/// };
+///
+/// let data = vec![
+///     vec!["Alice".to_string(), "Says \"hi\"".to_string()],
+///     vec!["Bob, Jr.".to_string(), "Line\nbreak".to_string()],
+/// ];
+/// let columns = vec![Column::index("Name", 0), Column::index("Note", 1)];
+///
+/// assert_eq!(
+///     to_csv(&data.iter().collect::<Vec<_>>(), &columns),
+///     "Name,Note\r\nAlice,\"Says \"\"hi\"\"\"\r\n\"Bob, Jr.\",\"Line\nbreak\"\r\n"
+/// );
+/// ```
+pub fn to_csv<T>(rows: &[&T], columns: &[Column<T>]) -> String {
+    let record = |fields: Vec<String>| {
+        let fields = fields.iter().map(|field| csv_field(field)).collect::<Vec<_>>();
+        format!("{}\r\n", fields.join(","))
+    };
+
+    let header = record(columns.iter().map(|column| column.header().to_string()).collect());
+    rows.iter().fold(header, |csv, row| csv + &record(columns.iter().map(|column| column.value(row)).collect()))
+}
+
+/// Serializes rows to a JSON array with an object per row.
+///
+/// Every object maps the column headers to the text values of the row, in the order of the
+/// columns.
+///
+/// # Parameters
+/// - `rows`: The rows to serialize, in order.
+/// - `columns`: The columns to serialize for every row.
+///
+/// # Returns
+/// - The JSON text.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::{
+///     column::Column,
+///     export::to_json,
+/// };
+///
+/// let data = vec![vec!["Alice".to_string(), "31".to_string()], vec!["\"Bob\"".to_string()]];
+/// let columns = vec![Column::index("Name", 0), Column::index("Age", 1)];
+///
+/// assert_eq!(
+///     to_json(&data.iter().collect::<Vec<_>>(), &columns),
+///     r#"[{"Name":"Alice","Age":"31"},{"Name":"\"Bob\"","Age":""}]"#
+/// );
+/// ```
+pub fn to_json<T>(rows: &[&T], columns: &[Column<T>]) -> String {
+    let objects = rows
+        .iter()
+        .map(|row| {
+            let members = columns
+                .iter()
+                .map(|column| format!("{}:{}", json_string(column.header()), json_string(&column.value(row))))
+                .collect::<Vec<_>>();
+            format!("{{{}}}", members.join(","))
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", objects.join(","))
+}
+
+/// How long the object URL of a download stays valid, in milliseconds.
+pub const REVOKE_DELAY_MS: u32 = 10_000;
+
+/// Offers text to the user as a file download.
+///
+/// The text is wrapped in a `Blob`, linked through an object URL and downloaded by clicking a
+/// temporary link. The URL is revoked after `REVOKE_DELAY_MS`, once the browser picked up the
+/// download.
+///
+/// # Parameters
+/// - `file_name`: The name the file is saved under.
+/// - `mime_type`: The MIME type of the file.
+/// - `content`: The content of the file.
+///
+/// # Returns
+/// - `Ok(())` once the download was started, or the JavaScript error that prevented it.
+pub fn download(file_name: &str, mime_type: &str, content: &str) -> Result<(), JsValue> {
+    let mut options = BlobPropertyBag::new();
+    options.type_(mime_type);
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let link = gloo::utils::document().create_element("a")?.dyn_into::<HtmlAnchorElement>()?;
+    link.set_href(&url);
+    link.set_download(file_name);
+    link.click();
+
+    // Revoking the URL right away can cancel the download in some browsers
+    Timeout::new(REVOKE_DELAY_MS, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
+
+/// Quotes a CSV field if it contains a comma, a double quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Encodes a string as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if u32::from(c) < 0x20 => json.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
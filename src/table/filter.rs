@@ -0,0 +1,213 @@
+//! Table Filter Module
+//!
+//! This module provides the filtering used by the `Table` component: a global search over all
+//! columns and per-column filters that match the text value of a cell. Filters are applied before
+//! the rows are sorted and paged.
+
+use std::collections::BTreeMap;
+
+use super::column::Column;
+
+/// The filter input offered for a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterKind {
+    /// The column cannot be filtered.
+    #[default]
+    None,
+    /// A text input keeping the rows whose value contains the text, ignoring case.
+    Text,
+    /// A select keeping the rows whose value equals one of the distinct values of the column.
+    Select,
+    /// Two inputs for a minimum and a maximum, keeping the rows whose value lies within the
+    /// inclusive range.
+    Range,
+}
+
+/// Where the rows of a `Table` are filtered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterMode {
+    /// The table filters its rows itself.
+    #[default]
+    Client,
+    /// The rows are filtered by the server and rendered as they are given. Changing a filter only
+    /// emits `on_filter`, so the filtered rows can be requested.
+    Server,
+}
+
+/// The value of a column filter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    /// Matches values containing the text, ignoring case.
+    Text(String),
+    /// Matches values equal to the text.
+    Select(String),
+    /// Matches numeric values within the inclusive range. The bounds are kept as they were typed
+    /// and parsed when filtering, so partial input such as `"-"` or `"1."` is not lost. A bound
+    /// that is empty or not a number is unbounded.
+    Range { min: String, max: String },
+}
+
+impl FilterValue {
+    /// Checks whether the filter has no effect, e.g. an empty text.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FilterValue::Text(text) => text.trim().is_empty(),
+            FilterValue::Select(_) => false,
+            FilterValue::Range { min, max } => min.trim().is_empty() && max.trim().is_empty(),
+        }
+    }
+
+    /// Returns the parsed bounds of a range filter, e.g. to filter the rows on the server.
+    ///
+    /// # Returns
+    /// - The minimum and maximum, each `None` when it is unbounded, or `None` for other filters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::table::filter::FilterValue;
+    ///
+    /// let range = FilterValue::Range { min: "-1.5".into(), max: "-".into() };
+    /// assert_eq!(range.bounds(), Some((Some(-1.5), None)));
+    /// ```
+    pub fn bounds(&self) -> Option<(Option<f64>, Option<f64>)> {
+        match self {
+            FilterValue::Range { min, max } => Some((parse_bound(min), parse_bound(max))),
+            FilterValue::Text(_) | FilterValue::Select(_) => None,
+        }
+    }
+
+    /// Checks whether a cell value passes the filter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::table::filter::FilterValue;
+    ///
+    /// assert!(FilterValue::Text("ali".into()).matches("Alice"));
+    /// assert!(FilterValue::Select("Alice".into()).matches("Alice"));
+    /// assert!(!FilterValue::Select("Ali".into()).matches("Alice"));
+    ///
+    /// let adults = FilterValue::Range { min: "18".into(), max: String::new() };
+    /// assert!(adults.matches("31"));
+    /// assert!(!adults.matches("9"));
+    /// assert!(!adults.matches("unknown"));
+    ///
+    /// let typing = FilterValue::Range { min: "-".into(), max: String::new() };
+    /// assert!(!typing.is_empty());
+    /// assert!(typing.matches("unknown"));
+    /// ```
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            FilterValue::Text(text) => contains_ignore_case(value, text.trim()),
+            FilterValue::Select(selected) => value == selected,
+            FilterValue::Range { min, max } => {
+                let (min, max) = (parse_bound(min), parse_bound(max));
+                match value.trim().parse::<f64>() {
+                    Ok(number) => min.is_none_or(|min| number >= min) && max.is_none_or(|max| number <= max),
+                    Err(_) => min.is_none() && max.is_none(),
+                }
+            }
+        }
+    }
+}
+
+/// The filters of a `Table`: a global search and a filter per column index.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::{
+///     column::Column,
+///     filter::{
+///         FilterValue,
+///         TableFilter,
+///     },
+/// };
+///
+/// let columns = vec![Column::index("Name", 0), Column::index("Age", 1)];
+/// let alice = vec!["Alice".to_string(), "31".to_string()];
+/// let bob = vec!["Bob".to_string(), "9".to_string()];
+///
+/// let filter = TableFilter::default()
+///     .with_column(1, Some(FilterValue::Range { min: "18".into(), max: String::new() }));
+/// assert!(filter.matches(&alice, &columns));
+/// assert!(!filter.matches(&bob, &columns));
+///
+/// let filter = TableFilter::default().with_search("BO");
+/// assert!(filter.matches(&bob, &columns));
+/// assert!(!filter.matches(&alice, &columns));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableFilter {
+    /// The text searched for in all columns, ignoring case.
+    pub search: String,
+    /// The filters of the columns, by column index.
+    pub columns: BTreeMap<usize, FilterValue>,
+}
+
+impl TableFilter {
+    /// Sets the global search text.
+    pub fn with_search(mut self, search: impl Into<String>) -> Self {
+        self.search = search.into();
+        self
+    }
+
+    /// Sets or, with `None` or an empty value, removes the filter of a column.
+    pub fn with_column(mut self, column: usize, value: Option<FilterValue>) -> Self {
+        match value.filter(|value| !value.is_empty()) {
+            Some(value) => self.columns.insert(column, value),
+            None => self.columns.remove(&column),
+        };
+        self
+    }
+
+    /// Checks whether no filter is active.
+    pub fn is_empty(&self) -> bool {
+        self.search.trim().is_empty() && self.columns.is_empty()
+    }
+
+    /// Checks whether a row passes the search and all column filters.
+    ///
+    /// Filters of columns that do not exist are ignored.
+    pub fn matches<T>(&self, row: &T, columns: &[Column<T>]) -> bool {
+        let search = self.search.trim();
+        let found = search.is_empty() || columns.iter().any(|column| contains_ignore_case(&column.value(row), search));
+
+        found
+            && self
+                .columns
+                .iter()
+                .all(|(index, value)| columns.get(*index).is_none_or(|column| value.matches(&column.value(row))))
+    }
+}
+
+/// Returns the distinct values of a column, ordered by the column's `SortKind`.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::{
+///     column::Column,
+///     filter::distinct_values,
+/// };
+///
+/// let data = vec![vec!["b".to_string()], vec!["a".to_string()], vec!["b".to_string()]];
+/// assert_eq!(distinct_values(&data, &Column::index("Letter", 0)), vec!["a", "b"]);
+/// ```
+pub fn distinct_values<T>(rows: &[T], column: &Column<T>) -> Vec<String> {
+    let mut values = rows.iter().map(|row| column.value(row)).collect::<Vec<_>>();
+    values.sort_by(|a, b| column.sort_kind.compare(a, b));
+    values.dedup();
+    values
+}
+
+/// Parses a bound of a range filter, treating empty and partial input as unbounded.
+fn parse_bound(bound: &str) -> Option<f64> {
+    bound.trim().parse().ok()
+}
+
+/// Checks whether `value` contains `text`, ignoring case.
+fn contains_ignore_case(value: &str, text: &str) -> bool {
+    value.to_lowercase().contains(&text.to_lowercase())
+}
@@ -0,0 +1,227 @@
+//! Table Grouping Module
+//!
+//! This module provides row grouping for the `Table` component. Rows are grouped by the text value
+//! of a column, and every group can summarize its rows with an `Aggregate` per column. Groups are
+//! ordered by the grouping column, taking its direction from the sort stack, while the rows inside
+//! a group keep the order of the sort, so grouping never contradicts the sort shown in the header.
+
+use std::{
+    fmt::Debug,
+    ops::Range,
+    rc::Rc,
+};
+
+use yew::Html;
+
+use super::{
+    column::Column,
+    sort::{
+        sort_rows_by,
+        SortKey,
+        SortMode,
+    },
+    SortDirection,
+};
+
+/// A summary of the values of a column within a group of rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aggregate {
+    /// The column is not summarized.
+    #[default]
+    None,
+    /// The number of rows with a non-empty value.
+    Count,
+    /// The sum of the numeric values. Values that are not numbers are skipped.
+    Sum,
+}
+
+impl Aggregate {
+    /// Summarizes the values of a column.
+    ///
+    /// # Parameters
+    /// - `values`: The text values of the column for every row of the group.
+    ///
+    /// # Returns
+    /// - The summary, or `None` for `Aggregate::None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::table::group::Aggregate;
+    ///
+    /// let values = vec!["4".to_string(), "".to_string(), "2.5".to_string(), "n/a".to_string()];
+    /// assert_eq!(Aggregate::Count.apply(&values), Some("3".to_string()));
+    /// assert_eq!(Aggregate::Sum.apply(&values), Some("6.5".to_string()));
+    /// assert_eq!(Aggregate::None.apply(&values), None);
+    /// ```
+    pub fn apply(&self, values: &[String]) -> Option<String> {
+        match self {
+            Aggregate::None => None,
+            Aggregate::Count => Some(values.iter().filter(|value| !value.trim().is_empty()).count().to_string()),
+            Aggregate::Sum => {
+                let sum = values
+                    .iter()
+                    .filter_map(|value| value.trim().parse::<f64>().ok())
+                    .filter(|number| number.is_finite())
+                    .sum::<f64>();
+                Some(sum.to_string())
+            }
+        }
+    }
+}
+
+/// A run of consecutive rows sharing the value of the grouping column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowGroup {
+    /// The value of the grouping column shared by the rows.
+    pub value: String,
+    /// The positions of the rows of the group.
+    pub range: Range<usize>,
+}
+
+impl RowGroup {
+    /// Returns the number of rows in the group.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns whether the group has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+}
+
+/// Renders the detail panel of an expanded row.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::html;
+/// use zirv_ui::table::group::RowDetail;
+///
+/// struct User {
+///     bio: String,
+/// }
+///
+/// let detail = RowDetail::new(|user: &User| html! { <p>{&user.bio}</p> });
+/// ```
+pub struct RowDetail<T>(Rc<dyn Fn(&T) -> Html>);
+
+impl<T> RowDetail<T> {
+    /// Creates a new row detail from a function rendering the panel of a row.
+    pub fn new(detail: impl Fn(&T) -> Html + 'static) -> Self {
+        Self(Rc::new(detail))
+    }
+
+    /// Renders the detail panel of a row.
+    pub fn render(&self, row: &T) -> Html {
+        (self.0)(row)
+    }
+}
+
+impl<T> Clone for RowDetail<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for RowDetail<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> Debug for RowDetail<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RowDetail").finish_non_exhaustive()
+    }
+}
+
+/// Groups sorted rows by the value of a column.
+///
+/// The rows are reordered so that every group is contiguous. Groups are ordered by the grouping
+/// column in the direction it has in the sort stack, or ascending when it is not sorted, and the
+/// rows within a group keep their order.
+///
+/// # Parameters
+/// - `rows`: The sorted rows to group.
+/// - `columns`: The columns of the table.
+/// - `group_by`: The index of the grouping column.
+/// - `stack`: The sort stack the rows are sorted by.
+///
+/// # Returns
+/// - The groups in display order, or no groups if the column does not exist.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::{
+///     column::Column,
+///     group::group_rows,
+///     sort::SortKey,
+///     SortDirection,
+/// };
+///
+/// let data = vec![
+///     vec!["Sales".to_string(), "Bob".to_string()],
+///     vec!["Admin".to_string(), "Carol".to_string()],
+///     vec!["Sales".to_string(), "Alice".to_string()],
+/// ];
+/// let columns = vec![Column::index("Team", 0), Column::index("Name", 1)];
+///
+/// let mut rows = data.iter().collect::<Vec<_>>();
+/// let groups = group_rows(&mut rows, &columns, 0, &[SortKey::new(0, SortDirection::Descending)]);
+///
+/// assert_eq!(
+///     groups.iter().map(|group| group.value.as_str()).collect::<Vec<_>>(),
+///     vec!["Sales", "Admin"]
+/// );
+/// assert_eq!(groups[0].range, 0..2);
+/// assert_eq!(
+///     rows.iter().map(|row| row[1].as_str()).collect::<Vec<_>>(),
+///     vec!["Bob", "Alice", "Carol"]
+/// );
+/// ```
+pub fn group_rows<T>(rows: &mut [&T], columns: &[Column<T>], group_by: usize, stack: &[SortKey]) -> Vec<RowGroup> {
+    let mut grouped = rows.to_vec();
+    let groups = group_rows_by(&mut grouped, columns, group_by, stack, SortMode::Client, |row| *row);
+    rows.copy_from_slice(&grouped);
+    groups
+}
+
+/// Groups items that each hold a row, such as rows paired with their key, by the value of a column.
+///
+/// With `SortMode::Server` the items are not reordered: every run of consecutive rows sharing a
+/// value becomes a group, so the server has to deliver the rows of a group next to each other.
+pub(crate) fn group_rows_by<R, T>(
+    items: &mut Vec<R>,
+    columns: &[Column<T>],
+    group_by: usize,
+    stack: &[SortKey],
+    mode: SortMode,
+    row: impl Fn(&R) -> &T,
+) -> Vec<RowGroup> {
+    let Some(column) = columns.get(group_by) else {
+        return Vec::new();
+    };
+
+    if mode == SortMode::Client {
+        let direction = stack
+            .iter()
+            .find(|key| key.column == group_by && key.direction != SortDirection::None)
+            .map_or(SortDirection::Ascending, |key| key.direction);
+
+        // The sort is stable, so the rows of a group stay in the order of the sort stack
+        sort_rows_by(items, columns, &[SortKey::new(group_by, direction)], &row);
+    }
+
+    let mut groups = Vec::<RowGroup>::new();
+    for (index, item) in items.iter().enumerate() {
+        let value = column.value(row(item));
+        match groups.last_mut() {
+            Some(group) if group.value == value => group.range.end = index + 1,
+            _ => groups.push(RowGroup { value, range: index..index + 1 }),
+        }
+    }
+    groups
+}
@@ -0,0 +1,63 @@
+//! Table Layout Module
+//!
+//! This module provides the arithmetic behind the column layout of the `Table` component: the
+//! offsets that keep frozen columns in place while the table scrolls horizontally, and the widths
+//! of columns resized by dragging their header edge.
+
+/// The narrowest width a column can be resized to, in pixels.
+pub const MIN_COLUMN_WIDTH: f64 = 40.0;
+
+/// Computes the left offsets of the frozen cells of a row.
+///
+/// Every frozen cell sticks to the left edge of the scroll container, shifted by the widths of
+/// the frozen cells before it.
+///
+/// # Parameters
+/// - `widths`: The rendered widths of the cells of a row, in pixels.
+/// - `frozen`: The number of frozen cells at the start of the row.
+///
+/// # Returns
+/// - The left offset of each frozen cell. Cells without a known width are left out.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::layout::frozen_offsets;
+///
+/// assert_eq!(frozen_offsets(&[40.0, 120.0, 80.0, 200.0], 3), vec![0.0, 40.0, 160.0]);
+/// ```
+pub fn frozen_offsets(widths: &[f64], frozen: usize) -> Vec<f64> {
+    widths
+        .iter()
+        .take(frozen)
+        .scan(0.0, |left, width| {
+            let offset = *left;
+            *left += width;
+            Some(offset)
+        })
+        .collect()
+}
+
+/// Computes the width of a column being resized.
+///
+/// # Parameters
+/// - `start_width`: The width of the column when dragging started, in pixels.
+/// - `delta`: How far the pointer moved horizontally since then, in pixels.
+///
+/// # Returns
+/// - The new width, never narrower than `MIN_COLUMN_WIDTH`.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::layout::{
+///     resize_width,
+///     MIN_COLUMN_WIDTH,
+/// };
+///
+/// assert_eq!(resize_width(120.0, 30.0), 150.0);
+/// assert_eq!(resize_width(120.0, -500.0), MIN_COLUMN_WIDTH);
+/// ```
+pub fn resize_width(start_width: f64, delta: f64) -> f64 {
+    (start_width + delta).max(MIN_COLUMN_WIDTH).round()
+}
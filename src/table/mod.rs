@@ -4,6 +4,9 @@
 //! supports various layout and styling options for the table, rows, and cells, such as border
 //! properties, width, height, padding, margin, text alignment, and overflow.
 //!
+//! The table is generic over the type of its rows. Each column is described by a `Column`, which
+//! extracts the displayed value from a row and can render it as arbitrary `Html`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -14,75 +17,289 @@
 //! };
 //! use zirv_ui::{
 //!     options::size::Width,
+//!     table::column::Column,
 //!     Table,
-//!     TableProps,
 //! };
 //!
+//! #[derive(Clone, PartialEq)]
+//! struct User {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
 //! #[function_component(App)]
 //! fn app() -> Html {
-//!     let headers = vec!["Header 1".to_string(), "Header 2".to_string(), "Header 3".to_string()];
-//!     let data = vec![
-//!         vec![
-//!             "Row 1, Cell 1".to_string(),
-//!             "Row 1, Cell 2".to_string(),
-//!             "Row 1, Cell 3".to_string(),
-//!         ],
-//!         vec![
-//!             "Row 2, Cell 1".to_string(),
-//!             "Row 2, Cell 2".to_string(),
-//!             "Row 2, Cell 3".to_string(),
-//!         ],
+//!     let columns = vec![
+//!         Column::new("Name", |user: &User| user.name.clone()),
+//!         Column::new("Age", |user: &User| user.age.to_string())
+//!             .with_comparator(|a: &User, b: &User| a.age.cmp(&b.age)),
+//!         Column::new("Profile", |user: &User| user.name.clone()).with_renderer(
+//!             |user: &User| html! { <a href={format!("/users/{}", user.name)}>{"View"}</a> },
+//!         ),
 //!     ];
+//!     let data =
+//!         vec![User { name: "Alice".into(), age: 31 }, User { name: "Bob".into(), age: 9 }];
 //!
 //!     html! {
-//!         <Table headers={headers} data={data} table_width={Width::Full} />
+//!         <Table<User> columns={columns} data={data} table_width={Width::Full} />
 //!     }
 //! }
 //! ```
 
-use std::cmp::Ordering;
+pub mod column;
+pub mod export;
+pub mod filter;
+pub mod group;
+pub mod layout;
+pub mod selection;
+pub mod sort;
+
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    collections::HashSet,
+    rc::Rc,
+};
 
+use gloo::{
+    events::EventListener,
+    timers::callback::Timeout,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{
+    Element,
+    HtmlElement,
+    HtmlInputElement,
+    HtmlSelectElement,
+};
 use yew::{
     classes,
     function_component,
     html,
+    use_effect,
+    use_effect_with,
+    use_force_update,
+    use_mut_ref,
+    use_node_ref,
     use_state_eq,
     Callback,
+    Event,
     Html,
+    InputEvent,
+    MouseEvent,
     Properties,
+    TargetCast,
 };
 
-use crate::options::{
-    border::{
-        BorderRadius,
-        BorderStyle,
-        BorderWidth,
+use self::{
+    column::Column,
+    export::{
+        download,
+        ExportFormat,
+    },
+    filter::{
+        distinct_values,
+        FilterKind,
+        FilterMode,
+        FilterValue,
+        TableFilter,
+    },
+    group::{
+        group_rows_by,
+        Aggregate,
+        RowDetail,
+        RowGroup,
+    },
+    layout::{
+        frozen_offsets,
+        resize_width,
+    },
+    selection::{
+        select_range,
+        toggle_all,
+        toggle_row,
+        RowKey,
+        SelectionMode,
+    },
+    sort::{
+        sort_rows_by,
+        toggle_sort,
+        SortKey,
+        SortMode,
+    },
+};
+use crate::{
+    options::{
+        border::{
+            BorderRadius,
+            BorderStyle,
+            BorderWidth,
+        },
+        font::TextAlign,
+        overflow::Overflow,
+        size::{
+            Height,
+            Width,
+        },
+    },
+    pagination::{
+        default_page_sizes,
+        state::{
+            PaginationState,
+            PAGE_SIZES,
+        },
+        Pagination,
+        PaginationMode,
     },
-    font::TextAlign,
-    overflow::Overflow,
-    size::{
-        Height,
-        Width,
+    virtualize::{
+        use_virtual_window::use_virtual_window,
+        RowHeight,
     },
 };
 
-#[derive(Clone, Copy, PartialEq)]
+/// The direction a column of a `Table` is sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortDirection {
     Ascending,
     Descending,
     None,
 }
 
+impl SortDirection {
+    /// Returns the direction following this one when the header of a column is clicked.
+    pub fn next(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::None,
+            SortDirection::None => SortDirection::Ascending,
+        }
+    }
+
+    /// Applies the direction to the ascending `ordering` of two rows.
+    pub fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
+            SortDirection::None => Ordering::Equal,
+        }
+    }
+}
+
 /// Properties for the `Table` component.
-#[derive(Clone, PartialEq, Properties, Default)]
-pub struct TableProps {
-    /// The headers for the table.
-    pub headers: Vec<String>,
-    /// The data for the table.
-    pub data: Vec<Vec<String>>,
-    /// Callback for when a column is sorted
+#[derive(Clone, PartialEq, Properties)]
+pub struct TableProps<T: PartialEq> {
+    /// The columns of the table.
+    pub columns: Vec<Column<T>>,
+    /// The rows of the table.
+    pub data: Vec<T>,
+    /// Callback for when a column is sorted. Shift-clicking a header sorts by several columns.
     #[prop_or_default]
     pub on_sort: Option<Callback<(usize, SortDirection)>>,
+    /// Callback for when the sort changes, with the complete new sort stack.
+    #[prop_or_default]
+    pub on_sort_change: Option<Callback<Vec<SortKey>>>,
+    /// Whether the rows are sorted by the table or by the server. Default is `SortMode::Client`.
+    #[prop_or_default]
+    pub sort_mode: SortMode,
+    /// The current sort, owned by the parent. When set, clicking a header does not change the
+    /// sort by itself; the parent updates this prop from `on_sort_change` instead.
+    #[prop_or_default]
+    pub sort: Option<Vec<SortKey>>,
+    /// The sort the table starts with when `sort` is not set.
+    #[prop_or_default]
+    pub initial_sort: Vec<SortKey>,
+    /// Enables pagination kept by the table, starting with this page size.
+    #[prop_or_default]
+    pub page_size: Option<usize>,
+    /// The current pagination state, owned by the parent. When set, the table is paginated and
+    /// the parent updates this prop from `on_page_change`.
+    #[prop_or_default]
+    pub pagination: Option<PaginationState>,
+    /// Whether the rows are paged by the table or by the server. Default is
+    /// `PaginationMode::Client`.
+    #[prop_or_default]
+    pub pagination_mode: PaginationMode,
+    /// Callback for when the page or the page size changes, including the return to the first
+    /// page after the sort or the filters change.
+    #[prop_or_default]
+    pub on_page_change: Option<Callback<PaginationState>>,
+    /// The page sizes offered below a paginated table. Default is `[10, 25, 50, 100]`.
+    #[prop_or_else(default_page_sizes)]
+    pub page_sizes: Vec<usize>,
+    /// Whether to show a search box above the table, searching all columns.
+    #[prop_or_default]
+    pub searchable: bool,
+    /// Whether the rows are filtered by the table or by the server. Default is
+    /// `FilterMode::Client`.
+    #[prop_or_default]
+    pub filter_mode: FilterMode,
+    /// The current search and column filters, owned by the parent. When set, the parent updates
+    /// this prop from `on_filter`.
+    #[prop_or_default]
+    pub filter: Option<TableFilter>,
+    /// Callback for when the search or a column filter changes.
+    #[prop_or_default]
+    pub on_filter: Option<Callback<TableFilter>>,
+    /// The formats offered by export buttons above the table. Exports contain every row passing the
+    /// filters, in the order shown, including the rows on other pages.
+    #[prop_or_default]
+    pub export_formats: Vec<ExportFormat>,
+    /// The name of exported files, without the extension. Default is `"table"`.
+    #[prop_or(String::from("table"))]
+    pub export_file_name: String,
+    /// Callback for when the table was exported, with the format and the serialized data.
+    #[prop_or_default]
+    pub on_export: Option<Callback<(ExportFormat, String)>>,
+    /// Renders only the rows scrolled into view, with rows of this height. The table is placed in
+    /// a scroll container sized by `table_height` and `table_max_height`.
+    #[prop_or_default]
+    pub virtualize: Option<RowHeight>,
+    /// The number of extra rows rendered above and below the visible rows of a virtualized table.
+    /// Default is `5`.
+    #[prop_or(5)]
+    pub overscan: usize,
+    /// Whether the header stays at the top while the table scrolls. The table is placed in a
+    /// scroll container sized by `table_height` and `table_max_height`.
+    #[prop_or_default]
+    pub sticky_header: bool,
+    /// The number of leading columns that stay in place while the table scrolls horizontally.
+    #[prop_or_default]
+    pub frozen_columns: usize,
+    /// Whether columns can be resized by dragging the right edge of their header.
+    #[prop_or_default]
+    pub resizable_columns: bool,
+    /// Callback for when a column was resized, with the column index and its new width in pixels.
+    #[prop_or_default]
+    pub on_column_resize: Option<Callback<(usize, f64)>>,
+    /// How rows can be selected. Default is `SelectionMode::None`.
+    #[prop_or_default]
+    pub selection: SelectionMode,
+    /// Extracts the stable key of a row. Defaults to the index of the row in `data`.
+    #[prop_or_default]
+    pub row_key: Option<RowKey<T>>,
+    /// The keys of the selected rows, owned by the parent. When set, the parent updates this prop
+    /// from `on_selection_change`.
+    #[prop_or_default]
+    pub selected: Option<Vec<String>>,
+    /// Callback for when the selection changes, with the keys of all selected rows.
+    #[prop_or_default]
+    pub on_selection_change: Option<Callback<Vec<String>>>,
+    /// Renders the detail panel shown below a row once it is expanded. Adds a leading column with
+    /// a toggle button to every row.
+    #[prop_or_default]
+    pub row_detail: Option<RowDetail<T>>,
+    /// The keys of the expanded rows, owned by the parent. When set, the parent updates this prop
+    /// from `on_expand_change`.
+    #[prop_or_default]
+    pub expanded: Option<Vec<String>>,
+    /// Callback for when a row is expanded or collapsed, with the keys of all expanded rows.
+    #[prop_or_default]
+    pub on_expand_change: Option<Callback<Vec<String>>>,
+    /// Groups the rows by the value of the column at this index, with a collapsible header per
+    /// group summarizing the columns set `with_aggregate`. With `SortMode::Server`, consecutive
+    /// rows sharing a value are grouped without reordering them.
+    #[prop_or_default]
+    pub group_by: Option<usize>,
     /// The border width of the table.
     #[prop_or_default]
     pub table_border_width: BorderWidth,
@@ -160,16 +377,110 @@ pub struct TableProps {
     pub cell_overflow: Overflow,
 }
 
+/// The document listeners of a column resize, dropped when the resize ends or the table unmounts.
+#[derive(Default)]
+struct ResizeListeners {
+    on_move: Option<EventListener>,
+    on_end: Option<EventListener>,
+    clear_suppress: Option<Timeout>,
+}
+
 /// The `Table` component.
 ///
 /// The `Table` component is used to display tabular data with customizable properties for the
 /// table, rows, and cells. It supports various styling options such as border properties, width,
 /// height, padding, margin, text alignment, and overflow.
 ///
+/// Clicking a header sorts the table by that column, cycling through ascending, descending and
+/// unsorted. Shift-clicking adds the column to the current sort instead, so ties are broken by the
+/// columns sorted before it. How values are compared is set per column with a `SortKind` or a
+/// custom comparator.
+///
+/// The sort is kept by the table unless the parent passes it in the `sort` prop. With
+/// `SortMode::Server` the rows are rendered in the order they are given, so data sorted by a
+/// backend is shown exactly as returned.
+///
+/// Setting `page_size` or `pagination` renders a `Pagination` below the table. In
+/// `PaginationMode::Client` the current page is sliced out of the sorted rows, while in
+/// `PaginationMode::Server` the rows are expected to be the current page and the total number of
+/// rows comes from the `pagination` state.
+///
+/// With a `SelectionMode` other than `None`, a leading checkbox column selects rows. In
+/// `SelectionMode::Multi` the header checkbox selects or deselects the rows currently shown, and
+/// shift-clicking a checkbox selects the range of rows since the previously clicked one. Rows are
+/// identified by their `row_key`, so the selection is kept when the table is sorted or paged.
+///
+/// Columns created `with_filter` get a filter input in a second header row, and `searchable` adds a
+/// search box above the table. Rows are filtered before they are sorted and paged, and the
+/// select-all checkbox only affects the rows that pass the filters.
+///
+/// Every format in `export_formats` gets a button above the table that downloads the rows passing
+/// the filters, in their sorted order, as CSV or JSON. The serialized data is also passed to
+/// `on_export`, e.g. to upload it instead.
+///
+/// For thousands of rows, `virtualize` renders only the rows inside the scrolled viewport, with
+/// spacer rows taking up the height of the others. The table then scrolls within a container
+/// sized by `table_height` and `table_max_height`.
+///
+/// The same scroll container is used for a `sticky_header` and for `frozen_columns`, which stick
+/// to the top and left edge while scrolling. With `resizable_columns`, dragging the right edge of a
+/// header resizes its column; the new width is reported through `on_column_resize` and can be
+/// restored with `Column::with_width`.
+///
+/// A `row_detail` adds a toggle button to every row that expands a panel below it. With `group_by`
+/// the rows are grouped by the value of a column, each group under a header showing its number of
+/// rows and the `Aggregate` of every column. Groups follow the direction the grouping column is
+/// sorted in, and the rows inside a group follow the rest of the sort. With `SortMode::Server` the
+/// rows are grouped in the order they are given, so the server has to return the rows of a group
+/// next to each other. Clicking a group header
+/// collapses the group. Virtualization is turned off for grouped tables and tables with row
+/// details, whose rows do not share a single height.
+///
 /// # Properties
 ///
-/// - `headers`: The headers for the table.
-/// - `data`: The data for the table.
+/// - `columns`: The columns of the table.
+/// - `data`: The rows of the table.
+/// - `on_sort`: Callback for when a column is sorted, with the column index and its new direction.
+/// - `on_sort_change`: Callback for when the sort changes, with the complete new sort stack.
+/// - `sort_mode`: Whether the rows are sorted by the table or by the server. Default is
+///   `SortMode::Client`.
+/// - `sort`: The current sort, owned by the parent.
+/// - `initial_sort`: The sort the table starts with when `sort` is not set.
+/// - `page_size`: Enables pagination kept by the table, starting with this page size.
+/// - `pagination`: The current pagination state, owned by the parent.
+/// - `pagination_mode`: Whether the rows are paged by the table or by the server. Default is
+///   `PaginationMode::Client`.
+/// - `on_page_change`: Callback for when the page or the page size changes.
+/// - `page_sizes`: The page sizes offered below a paginated table. Default is `[10, 25, 50, 100]`.
+/// - `searchable`: Whether to show a search box above the table, searching all columns.
+/// - `filter_mode`: Whether the rows are filtered by the table or by the server. Default is
+///   `FilterMode::Client`.
+/// - `filter`: The current search and column filters, owned by the parent.
+/// - `on_filter`: Callback for when the search or a column filter changes.
+/// - `export_formats`: The formats offered by export buttons above the table.
+/// - `export_file_name`: The name of exported files, without the extension. Default is `"table"`.
+/// - `on_export`: Callback for when the table was exported, with the format and the serialized
+///   data.
+/// - `virtualize`: Renders only the rows scrolled into view, with rows of this height.
+/// - `overscan`: The number of extra rows rendered around the visible rows of a virtualized table.
+///   Default is `5`.
+/// - `sticky_header`: Whether the header stays at the top while the table scrolls.
+/// - `frozen_columns`: The number of leading columns that stay in place while the table scrolls
+///   horizontally.
+/// - `resizable_columns`: Whether columns can be resized by dragging the right edge of their
+///   header.
+/// - `on_column_resize`: Callback for when a column was resized, with the column index and its new
+///   width in pixels.
+/// - `selection`: How rows can be selected. Default is `SelectionMode::None`.
+/// - `row_key`: Extracts the stable key of a row. Defaults to the index of the row in `data`.
+/// - `selected`: The keys of the selected rows, owned by the parent.
+/// - `on_selection_change`: Callback for when the selection changes, with the keys of all selected
+///   rows.
+/// - `row_detail`: Renders the detail panel shown below a row once it is expanded.
+/// - `expanded`: The keys of the expanded rows, owned by the parent.
+/// - `on_expand_change`: Callback for when a row is expanded or collapsed, with the keys of all
+///   expanded rows.
+/// - `group_by`: Groups the rows by the value of the column at this index.
 /// - `table_border_width`: The border width of the table.
 /// - `table_border_radius`: The border radius of the table.
 /// - `table_border_style`: The border style of the table.
@@ -196,9 +507,74 @@ pub struct TableProps {
 /// - `cell_text_align`: The text alignment of the cells.
 /// - `cell_overflow`: The overflow property of the cells. Default is `Overflow::Auto`.
 #[function_component(Table)]
-pub fn table(props: &TableProps) -> Html {
-    let sort_column = use_state_eq(|| None::<usize>);
-    let sort_direction = use_state_eq(|| SortDirection::None);
+pub fn table<T>(props: &TableProps<T>) -> Html
+where
+    T: PartialEq + 'static,
+{
+    let initial_sort = props.initial_sort.clone();
+    let internal_sort = use_state_eq(move || initial_sort);
+    let sort = props.sort.clone().unwrap_or_else(|| (*internal_sort).clone());
+
+    let page_size = props.page_size;
+    let internal_page = use_state_eq(move || PaginationState::new(page_size.unwrap_or(PAGE_SIZES[0]), 0));
+    let page = props.pagination.or_else(|| props.page_size.map(|_| *internal_page));
+
+    let internal_filter = use_state_eq(TableFilter::default);
+    let filter = props.filter.clone().unwrap_or_else(|| (*internal_filter).clone());
+
+    let internal_selected = use_state_eq(Vec::<String>::new);
+    // Shared by the select cell of every row, so a render copies the selection only once
+    let selected = Rc::<[String]>::from(props.selected.clone().unwrap_or_else(|| (*internal_selected).clone()));
+    let selected_keys = selected.iter().map(String::as_str).collect::<HashSet<_>>();
+    let selection_anchor = use_mut_ref(|| None::<String>);
+    let select_all_ref = use_node_ref();
+
+    let internal_expanded = use_state_eq(Vec::<String>::new);
+    let expanded = Rc::<[String]>::from(props.expanded.clone().unwrap_or_else(|| (*internal_expanded).clone()));
+    let expanded_keys = expanded.iter().map(String::as_str).collect::<HashSet<_>>();
+    let collapsed_groups = use_state_eq(Vec::<String>::new);
+
+    let header_row = use_node_ref();
+    let header_widths = use_state_eq(Vec::<f64>::new);
+    let column_widths = use_state_eq(Vec::<Option<f64>>::new);
+    let resize_listeners = use_mut_ref(ResizeListeners::default);
+    let suppress_sort = use_mut_ref(|| false);
+
+    {
+        let resize_listeners = resize_listeners.clone();
+        use_effect_with((), move |_| move || drop(resize_listeners.take()));
+    }
+
+    // The expand and select cells come before the cells of the columns
+    let expand_offset = usize::from(props.row_detail.is_some());
+    let select_offset = expand_offset + usize::from(props.selection != SelectionMode::None);
+    let frozen_cells = if props.frozen_columns > 0 { props.frozen_columns + select_offset } else { 0 };
+
+    {
+        // Frozen cells are offset by the rendered widths of the cells before them, measured again
+        // whenever the header cells or their widths change
+        let header_row = header_row.clone();
+        let header_widths = header_widths.clone();
+        let headers = props.columns.iter().map(|column| column.header().to_string()).collect::<Vec<_>>();
+
+        use_effect_with((frozen_cells, headers, (*column_widths).clone()), move |(frozen_cells, _, _)| {
+            if let Some(row) = header_row.cast::<Element>().filter(|_| *frozen_cells > 0) {
+                let cells = row.children();
+                let widths = (0..cells.length())
+                    .filter_map(|index| cells.item(index)?.dyn_into::<HtmlElement>().ok())
+                    .map(|cell| f64::from(cell.offset_width()))
+                    .collect::<Vec<_>>();
+                header_widths.set(widths);
+            }
+        });
+    }
+
+    let offsets = frozen_offsets(&header_widths, frozen_cells);
+    let frozen = |position: usize| {
+        let class = (position < frozen_cells).then_some("table-frozen");
+        let style = offsets.get(position).map(|left| format!("left: {}px;", left));
+        (class, style)
+    };
 
     let table_classes = classes! {
         &props.table_border_width,
@@ -233,83 +609,720 @@ pub fn table(props: &TableProps) -> Html {
         &props.cell_text_align,
     };
 
+    let on_page_change = {
+        let internal_page = internal_page.clone();
+        let controlled = props.pagination.is_some();
+        let on_page_change = props.on_page_change.clone();
+
+        Callback::from(move |new_page: PaginationState| {
+            if !controlled {
+                internal_page.set(new_page);
+            }
+
+            if let Some(callback) = on_page_change.as_ref() {
+                callback.emit(new_page);
+            }
+        })
+    };
+
+    // A new order or filter starts over at the first page, also for a parent owning the page
+    let reset_page = {
+        let on_page_change = on_page_change.clone();
+        Callback::from(move |_: ()| {
+            if let Some(page) = page.filter(|page| page.page != 0) {
+                on_page_change.emit(page.with_page(0));
+            }
+        })
+    };
+
     let headers = props
-        .headers
+        .columns
         .iter()
         .enumerate()
-        .map(|(index, header)| {
-            let sort_column = sort_column.clone();
-            let sort_direction = sort_direction.clone();
+        .map(|(index, column)| {
+            let sort = sort.clone();
+            let internal_sort = internal_sort.clone();
+            let controlled = props.sort.is_some();
+            let reset_page = reset_page.clone();
             let on_sort = props.on_sort.clone();
+            let on_sort_change = props.on_sort_change.clone();
 
-            let sort_indicator = if Some(index) == *sort_column {
-                match *sort_direction {
-                    SortDirection::Ascending => " ▲",
-                    SortDirection::Descending => " ▼",
-                    SortDirection::None => "",
-                }
-            } else {
-                ""
+            let position = sort.iter().position(|key| key.column == index);
+            let sort_indicator = match position.map(|position| sort[position].direction) {
+                Some(SortDirection::Ascending) => " ▲",
+                Some(SortDirection::Descending) => " ▼",
+                _ => "",
             };
+            // Only number the sorted columns when there is more than one of them
+            let sort_priority = position.filter(|_| sort.len() > 1).map(|position| position + 1);
 
-            let onclick = Callback::from(move |_| {
-                let new_direction = if Some(index) == *sort_column {
-                    match *sort_direction {
-                        SortDirection::Ascending => SortDirection::Descending,
-                        SortDirection::Descending => SortDirection::None,
-                        SortDirection::None => SortDirection::Ascending,
-                    }
-                } else {
-                    SortDirection::Ascending
-                };
+            let suppress_click = suppress_sort.clone();
+            let onclick = Callback::from(move |event: MouseEvent| {
+                // The click ending a column resize is not meant to sort
+                if suppress_click.replace(false) {
+                    return;
+                }
 
-                sort_column.set(Some(index));
-                sort_direction.set(new_direction);
+                let new_sort = toggle_sort(&sort, index, event.shift_key());
+                let new_direction =
+                    new_sort.iter().find(|key| key.column == index).map_or(SortDirection::None, |key| key.direction);
+
+                if !controlled {
+                    internal_sort.set(new_sort.clone());
+                }
+                reset_page.emit(());
 
                 if let Some(callback) = on_sort.as_ref() {
                     callback.emit((index, new_direction));
                 }
+                if let Some(callback) = on_sort_change.as_ref() {
+                    callback.emit(new_sort);
+                }
+            });
+
+            let resize_handle = props.resizable_columns.then(|| {
+                let column_widths = column_widths.clone();
+                let resize_listeners = resize_listeners.clone();
+                let suppress_sort = suppress_sort.clone();
+                let on_column_resize = props.on_column_resize.clone();
+
+                let onmousedown = Callback::from(move |event: MouseEvent| {
+                    event.prevent_default();
+                    event.stop_propagation();
+
+                    let Some(cell) = event
+                        .target_dyn_into::<Element>()
+                        .and_then(|target| target.closest("th").ok().flatten())
+                        .and_then(|cell| cell.dyn_into::<HtmlElement>().ok())
+                    else {
+                        return;
+                    };
+
+                    let start_x = f64::from(event.client_x());
+                    let start_width = f64::from(cell.offset_width());
+                    let width = Rc::new(Cell::new(start_width));
+                    let document = gloo::utils::document();
+
+                    let on_move = {
+                        let column_widths = column_widths.clone();
+                        let width = width.clone();
+                        EventListener::new(&document, "mousemove", move |event| {
+                            if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                                width.set(resize_width(start_width, f64::from(event.client_x()) - start_x));
+
+                                let mut widths = (*column_widths).clone();
+                                widths.resize(widths.len().max(index + 1), None);
+                                widths[index] = Some(width.get());
+                                column_widths.set(widths);
+                            }
+                        })
+                    };
+
+                    let on_end = {
+                        let resize_listeners = resize_listeners.clone();
+                        let suppress_sort = suppress_sort.clone();
+                        let on_column_resize = on_column_resize.clone();
+                        EventListener::once(&document, "mouseup", move |_| {
+                            // A click follows the mouseup when it is released over the header.
+                            // Clear the flag afterwards in case it is released elsewhere.
+                            *suppress_sort.borrow_mut() = true;
+                            let clear_suppress = Timeout::new(0, move || *suppress_sort.borrow_mut() = false);
+
+                            // The mouseup listener itself is running, so it is only replaced by
+                            // the next resize or dropped with the table
+                            let mut listeners = resize_listeners.borrow_mut();
+                            listeners.on_move = None;
+                            listeners.clear_suppress = Some(clear_suppress);
+                            drop(listeners);
+
+                            if let Some(callback) = on_column_resize.as_ref() {
+                                callback.emit((index, width.get()));
+                            }
+                        })
+                    };
+
+                    let mut listeners = resize_listeners.borrow_mut();
+                    listeners.on_move = Some(on_move);
+                    listeners.on_end = Some(on_end);
+                });
+
+                html! { <span class="table-resize-handle" aria-hidden="true" {onmousedown} /> }
             });
 
+            let (frozen_class, frozen_style) = frozen(index + select_offset);
+            let width = column_widths.get(index).copied().flatten().or(column.width);
+            let style = match (width.map(|width| format!("width: {}px;", width)), frozen_style) {
+                (Some(width), Some(left)) => Some(format!("{} {}", width, left)),
+                (width, left) => width.or(left),
+            };
+
             html! {
-                <th onclick={onclick}>
-                    {header}
+                <th class={classes!(frozen_class)} {style} onclick={onclick}>
+                    {column.header()}
                     {sort_indicator}
+                    if let Some(priority) = sort_priority {
+                        <sup class="table-sort-priority">{priority}</sup>
+                    }
+                    {resize_handle}
                 </th>
             }
         })
         .collect::<Html>();
 
-    let mut sorted_data = props.data.clone();
-    if let Some(column) = *sort_column {
-        sorted_data.sort_by(|a, b| {
-            let comparison = a[column].cmp(&b[column]);
-            match *sort_direction {
-                SortDirection::Ascending => comparison,
-                SortDirection::Descending => comparison.reverse(),
-                SortDirection::None => Ordering::Equal,
+    let mut rows = props
+        .data
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| props.filter_mode == FilterMode::Server || filter.matches(*row, &props.columns))
+        .map(|(index, row)| (props.row_key.as_ref().map_or_else(|| index.to_string(), |key| key.key(row)), row))
+        .collect::<Vec<_>>();
+    if props.sort_mode == SortMode::Client {
+        sort_rows_by(&mut rows, &props.columns, &sort, |(_, row)| *row);
+    }
+    let groups = props
+        .group_by
+        .map(|group_by| group_rows_by(&mut rows, &props.columns, group_by, &sort, props.sort_mode, |(_, row)| *row))
+        .unwrap_or_default();
+    let is_collapsed = |index: usize| {
+        groups.iter().any(|group| group.range.contains(&index) && collapsed_groups.contains(&group.value))
+    };
+
+    let page = page.map(|page| match props.pagination_mode {
+        PaginationMode::Client => page.with_total(rows.len()),
+        PaginationMode::Server => page,
+    });
+    let page_range = match (page, props.pagination_mode) {
+        (Some(page), PaginationMode::Client) => page.range(),
+        _ => 0..rows.len(),
+    };
+    let visible = page_range
+        .clone()
+        .filter(|index| !is_collapsed(*index))
+        .map(|index| rows[index].0.clone())
+        .collect::<Rc<[String]>>();
+
+    let scroll_container = use_node_ref();
+    let body = use_node_ref();
+    let virtualize = props.virtualize.filter(|_| groups.is_empty() && props.row_detail.is_none());
+    let window =
+        use_virtual_window(scroll_container.clone(), body.clone(), page_range.len(), virtualize, props.overscan);
+    let column_count = props.columns.len() + select_offset;
+    let spacer = |height: f64| {
+        (height > 0.0).then(|| {
+            html! {
+                <tr class="table-spacer" aria-hidden="true">
+                    <td colspan={column_count.to_string()} style={format!("height: {}px", height)} />
+                </tr>
+            }
+        })
+    };
+
+    let set_filter = {
+        let internal_filter = internal_filter.clone();
+        let controlled = props.filter.is_some();
+        let reset_page = reset_page.clone();
+        let on_filter = props.on_filter.clone();
+
+        Callback::from(move |new_filter: TableFilter| {
+            if !controlled {
+                internal_filter.set(new_filter.clone());
+            }
+            // The current page may no longer exist once fewer rows pass the filters
+            reset_page.emit(());
+
+            if let Some(callback) = on_filter.as_ref() {
+                callback.emit(new_filter);
+            }
+        })
+    };
+
+    let search = props.searchable.then(|| {
+        let value = filter.search.clone();
+        let filter = filter.clone();
+        let set_filter = set_filter.clone();
+        let oninput = Callback::from(move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+            set_filter.emit(filter.clone().with_search(input.value()));
+        });
+
+        html! {
+            <input
+                class="table-search"
+                type="search"
+                placeholder="Search…"
+                aria-label="Search table"
+                {value}
+                {oninput}
+            />
+        }
+    });
+
+    // The rows are borrowed from the props and cannot be moved into the click handler, so a click
+    // only asks for one render, which serializes the export for the effect that downloads it
+    let export_request = use_mut_ref(|| None::<ExportFormat>);
+    let export_ready = use_mut_ref(|| None::<(ExportFormat, String)>);
+    let rerender = use_force_update();
+    if let Some(format) = export_request.borrow_mut().take() {
+        let export_rows = rows.iter().map(|(_, row)| *row).collect::<Vec<_>>();
+        *export_ready.borrow_mut() = Some((format, format.serialize(&export_rows, &props.columns)));
+    }
+    {
+        let export_ready = export_ready.clone();
+        let file_name = props.export_file_name.clone();
+        let on_export = props.on_export.clone();
+
+        use_effect(move || {
+            if let Some((format, content)) = export_ready.take() {
+                if let Err(error) =
+                    download(&format!("{}.{}", file_name, format.extension()), format.mime_type(), &content)
+                {
+                    log::error!("Failed to download the table export: {:?}", error);
+                }
+                if let Some(callback) = on_export.as_ref() {
+                    callback.emit((format, content));
+                }
             }
         });
     }
 
-    let data = sorted_data
+    let export_buttons = props
+        .export_formats
         .iter()
-        .map(|row| {
-            let cells = row.iter().map(|cell| html! { <td class={cell_classes.clone()}>{cell}</td> }).collect::<Html>();
-            html! { <tr class={row_classes.clone()}>{cells}</tr> }
+        .map(|format| {
+            let format = *format;
+            let export_request = export_request.clone();
+            let rerender = rerender.clone();
+            let onclick = Callback::from(move |_: MouseEvent| {
+                *export_request.borrow_mut() = Some(format);
+                rerender.force_update();
+            });
+
+            html! {
+                <button class="table-export" type="button" {onclick}>{format!("Export {}", format.label())}</button>
+            }
         })
         .collect::<Html>();
 
-    html! {
-        <table class={table_classes}>
-            <thead>
-                <tr>
-                    {headers}
+    let filter_row = props.columns.iter().any(|column| column.filter != FilterKind::None).then(|| {
+        let cells = props
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let (class, style) = frozen(index + select_offset);
+                html! {
+                    <th class={classes!(class)} {style}>
+                        {filter_cell(column, index, &filter, &props.data, &set_filter)}
+                    </th>
+                }
+            })
+            .collect::<Html>();
+        let (expand_class, expand_style) = frozen(0);
+        let (select_class, select_style) = frozen(expand_offset);
+
+        html! {
+            <tr class="table-filters">
+                if props.row_detail.is_some() {
+                    <th class={classes!(expand_class)} style={expand_style} />
+                }
+                if props.selection != SelectionMode::None {
+                    <th class={classes!(select_class)} style={select_style} />
+                }
+                {cells}
+            </tr>
+        }
+    });
+
+    let on_selection_change = {
+        let internal_selected = internal_selected.clone();
+        let controlled = props.selected.is_some();
+        let on_selection_change = props.on_selection_change.clone();
+
+        Callback::from(move |new_selected: Vec<String>| {
+            if !controlled {
+                internal_selected.set(new_selected.clone());
+            }
+
+            if let Some(callback) = on_selection_change.as_ref() {
+                callback.emit(new_selected);
+            }
+        })
+    };
+
+    let all_visible_selected = !visible.is_empty() && visible.iter().all(|key| selected_keys.contains(key.as_str()));
+    let some_visible_selected = visible.iter().any(|key| selected_keys.contains(key.as_str()));
+    {
+        // `indeterminate` is only available as a DOM property, not as an attribute
+        let select_all_ref = select_all_ref.clone();
+        use_effect_with(some_visible_selected && !all_visible_selected, move |indeterminate| {
+            if let Some(input) = select_all_ref.cast::<HtmlInputElement>() {
+                input.set_indeterminate(*indeterminate);
+            }
+        });
+    }
+
+    let expand_header = props.row_detail.is_some().then(|| {
+        let (class, style) = frozen(0);
+        html! { <th class={classes!("table-expand", class)} {style} /> }
+    });
+
+    let (select_class, select_style) = frozen(expand_offset);
+    let select_header = match props.selection {
+        SelectionMode::None => html! {},
+        SelectionMode::Single => html! { <th class={classes!("table-select", select_class)} style={select_style} /> },
+        SelectionMode::Multi => {
+            let selected = selected.clone();
+            let visible = visible.clone();
+            let on_selection_change = on_selection_change.clone();
+            let onclick =
+                Callback::from(move |_: MouseEvent| on_selection_change.emit(toggle_all(&selected, &visible)));
+
+            html! {
+                <th class={classes!("table-select", select_class)} style={select_style}>
+                    <input
+                        ref={select_all_ref}
+                        type="checkbox"
+                        aria-label="Select all rows"
+                        checked={all_visible_selected}
+                        {onclick}
+                    />
+                </th>
+            }
+        }
+    };
+
+    let on_expand_change = {
+        let internal_expanded = internal_expanded.clone();
+        let controlled = props.expanded.is_some();
+        let on_expand_change = props.on_expand_change.clone();
+
+        Callback::from(move |new_expanded: Vec<String>| {
+            if !controlled {
+                internal_expanded.set(new_expanded.clone());
+            }
+
+            if let Some(callback) = on_expand_change.as_ref() {
+                callback.emit(new_expanded);
+            }
+        })
+    };
+
+    // Renders a row, followed by its detail panel when it is expanded
+    let render_row = |key: &String, row: &T| {
+        let is_selected = selected_keys.contains(key.as_str());
+        let is_expanded = expanded_keys.contains(key.as_str());
+
+        let expand_cell = props.row_detail.is_some().then(|| {
+            let key = key.clone();
+            let expanded = expanded.clone();
+            let on_expand_change = on_expand_change.clone();
+            let onclick = Callback::from(move |_: MouseEvent| {
+                on_expand_change.emit(toggle_row(&expanded, &key, SelectionMode::Multi));
+            });
+
+            let (frozen_class, style) = frozen(0);
+            html! {
+                <td class={classes!("table-expand", frozen_class, cell_classes.clone())} {style}>
+                    <button
+                        class="table-expand-button"
+                        type="button"
+                        aria-label={if is_expanded { "Collapse row" } else { "Expand row" }}
+                        aria-expanded={is_expanded.to_string()}
+                        {onclick}
+                    >
+                        {if is_expanded { "▾" } else { "▸" }}
+                    </button>
+                </td>
+            }
+        });
+
+        let select_cell = if props.selection == SelectionMode::None {
+            html! {}
+        } else {
+            let key = key.clone();
+            let mode = props.selection;
+            let selected = selected.clone();
+            let visible = visible.clone();
+            let selection_anchor = selection_anchor.clone();
+            let on_selection_change = on_selection_change.clone();
+
+            let onclick = Callback::from(move |event: MouseEvent| {
+                let anchor = selection_anchor.borrow().clone();
+                let new_selected = match anchor {
+                    Some(anchor) if mode == SelectionMode::Multi && event.shift_key() => {
+                        select_range(&selected, &visible, &anchor, &key)
+                    }
+                    _ => toggle_row(&selected, &key, mode),
+                };
+
+                *selection_anchor.borrow_mut() = Some(key.clone());
+                on_selection_change.emit(new_selected);
+            });
+
+            let (frozen_class, style) = frozen(expand_offset);
+            html! {
+                <td class={classes!("table-select", frozen_class, cell_classes.clone())} {style}>
+                    <input type="checkbox" aria-label="Select row" checked={is_selected} {onclick} />
+                </td>
+            }
+        };
+
+        let cells = props
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let (frozen_class, style) = frozen(index + select_offset);
+                html! { <td class={classes!(frozen_class, cell_classes.clone())} {style}>{column.render(row)}</td> }
+            })
+            .collect::<Html>();
+
+        let row_html = html! {
+            <tr
+                key={key.clone()}
+                class={classes!(row_classes.clone(), is_selected.then_some("table-row-selected"))}
+                aria-selected={(props.selection != SelectionMode::None).then(|| is_selected.to_string())}
+            >
+                {expand_cell}
+                {select_cell}
+                {cells}
+            </tr>
+        };
+
+        let detail = props.row_detail.as_ref().filter(|_| is_expanded).map(|detail| {
+            html! {
+                <tr key={format!("{}-detail", key)} class="table-detail">
+                    <td colspan={column_count.to_string()} class={cell_classes.clone()}>{detail.render(row)}</td>
                 </tr>
-            </thead>
-            <tbody>
-                {data}
-            </tbody>
-        </table>
+            }
+        });
+
+        std::iter::once(row_html).chain(detail)
+    };
+
+    let group_header = |group: &RowGroup| {
+        let is_collapsed = collapsed_groups.contains(&group.value);
+        let onclick = {
+            let collapsed_groups = collapsed_groups.clone();
+            let value = group.value.clone();
+            Callback::from(move |_: MouseEvent| {
+                collapsed_groups.set(toggle_row(&collapsed_groups, &value, SelectionMode::Multi));
+            })
+        };
+
+        let leading = (0..select_offset)
+            .map(|position| {
+                let (frozen_class, style) = frozen(position);
+                html! { <td class={classes!(frozen_class, cell_classes.clone())} {style} /> }
+            })
+            .collect::<Html>();
+        let cells = props
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let content = if props.group_by == Some(index) {
+                    html! {
+                        <button
+                            class="table-group-toggle"
+                            type="button"
+                            aria-expanded={(!is_collapsed).to_string()}
+                            onclick={onclick.clone()}
+                        >
+                            {if is_collapsed { "▸ " } else { "▾ " }}
+                            {format!("{}: {}", column.header(), group.value)}
+                            <span class="table-group-count">{format!(" ({})", group.len())}</span>
+                        </button>
+                    }
+                } else {
+                    // Aggregates summarize the whole group, including rows on other pages
+                    let summary = (column.aggregate != Aggregate::None).then(|| {
+                        let values =
+                            rows[group.range.clone()].iter().map(|(_, row)| column.value(row)).collect::<Vec<_>>();
+                        column.aggregate.apply(&values).unwrap_or_default()
+                    });
+                    html! { {summary.unwrap_or_default()} }
+                };
+
+                let (frozen_class, style) = frozen(index + select_offset);
+                html! { <td class={classes!(frozen_class, cell_classes.clone())} {style}>{content}</td> }
+            })
+            .collect::<Html>();
+
+        html! {
+            <tr key={format!("group-{}", group.value)} class={classes!("table-group", row_classes.clone())}>
+                {leading}
+                {cells}
+            </tr>
+        }
+    };
+
+    let data = if groups.is_empty() {
+        rows[page_range.clone()][window.range()].iter().flat_map(|(key, row)| render_row(key, row)).collect::<Html>()
+    } else {
+        // Every group with rows on the current page gets a header, even when it started on an
+        // earlier page
+        groups
+            .iter()
+            .filter_map(|group| {
+                let start = group.range.start.max(page_range.start);
+                let end = group.range.end.min(page_range.end);
+                let group_rows = if collapsed_groups.contains(&group.value) { &[][..] } else { &rows[start..end] };
+
+                (start < end).then(|| {
+                    std::iter::once(group_header(group))
+                        .chain(group_rows.iter().flat_map(|(key, row)| render_row(key, row)))
+                })
+            })
+            .flatten()
+            .collect::<Html>()
+    };
+
+    let scrolls = props.virtualize.is_some() || props.sticky_header || props.frozen_columns > 0;
+
+    html! {
+        <>
+            if props.searchable || !props.export_formats.is_empty() {
+                <div class="table-toolbar">
+                    {search}
+                    {export_buttons}
+                </div>
+            }
+            <div
+                ref={scroll_container}
+                class={scrolls.then(|| {
+                    classes!("table-scroll", &props.table_height, &props.table_max_height, &Overflow::Auto)
+                })}
+            >
+                <table class={classes!(table_classes, props.resizable_columns.then_some("table-resizable"))}>
+                    <thead class={classes!(props.sticky_header.then_some("table-sticky-header"))}>
+                        <tr ref={header_row}>
+                            {expand_header}
+                            {select_header}
+                            {headers}
+                        </tr>
+                        {filter_row}
+                    </thead>
+                    <tbody ref={body}>
+                        {spacer(window.before)}
+                        {data}
+                        {spacer(window.after)}
+                    </tbody>
+                </table>
+            </div>
+            if let Some(page) = page {
+                <Pagination state={page} on_change={on_page_change} page_sizes={props.page_sizes.clone()} />
+            }
+        </>
+    }
+}
+
+/// Renders the filter input of a column for a cell of the second header row.
+fn filter_cell<T>(
+    column: &Column<T>,
+    index: usize,
+    filter: &TableFilter,
+    data: &[T],
+    set_filter: &Callback<TableFilter>,
+) -> Html {
+    let current = filter.columns.get(&index);
+    let label = format!("Filter {}", column.header());
+
+    let on_value = |to_value: fn(String, Option<&FilterValue>) -> Option<FilterValue>| {
+        let filter = filter.clone();
+        let set_filter = set_filter.clone();
+        move |value: String| {
+            let new_value = to_value(value, filter.columns.get(&index));
+            set_filter.emit(filter.clone().with_column(index, new_value));
+        }
+    };
+
+    match column.filter {
+        FilterKind::None => html! {},
+        FilterKind::Text => {
+            let value = match current {
+                Some(FilterValue::Text(text)) => text.clone(),
+                _ => String::new(),
+            };
+            let on_value = on_value(|value, _| Some(FilterValue::Text(value)));
+            let oninput = Callback::from(move |event: InputEvent| {
+                on_value(event.target_unchecked_into::<HtmlInputElement>().value());
+            });
+
+            html! {
+                <input class="table-filter" type="search" placeholder="Filter…" aria-label={label} {value} {oninput} />
+            }
+        }
+        FilterKind::Select => {
+            let selected = match current {
+                Some(FilterValue::Select(value)) => Some(value.as_str()),
+                _ => None,
+            };
+            let on_value = on_value(|value, _| (!value.is_empty()).then_some(FilterValue::Select(value)));
+            let onchange = Callback::from(move |event: Event| {
+                on_value(event.target_unchecked_into::<HtmlSelectElement>().value());
+            });
+
+            html! {
+                <select class="table-filter" aria-label={label} {onchange}>
+                    <option value="" selected={selected.is_none()}>{"All"}</option>
+                    {for distinct_values(data, column).into_iter().map(|value| html! {
+                        <option selected={selected == Some(value.as_str())} value={value.clone()}>{value}</option>
+                    })}
+                </select>
+            }
+        }
+        FilterKind::Range => {
+            let (min, max) = match current {
+                Some(FilterValue::Range { min, max }) => (min.clone(), max.clone()),
+                _ => (String::new(), String::new()),
+            };
+
+            // The bounds are kept as typed, so that partial numbers such as "-" or "1." survive
+            // the re-render
+            let on_min = on_value(|value, current| {
+                let max = match current {
+                    Some(FilterValue::Range { max, .. }) => max.clone(),
+                    _ => String::new(),
+                };
+                Some(FilterValue::Range { min: value, max })
+            });
+            let on_max = on_value(|value, current| {
+                let min = match current {
+                    Some(FilterValue::Range { min, .. }) => min.clone(),
+                    _ => String::new(),
+                };
+                Some(FilterValue::Range { min, max: value })
+            });
+            let oninput_min = Callback::from(move |event: InputEvent| {
+                on_min(event.target_unchecked_into::<HtmlInputElement>().value());
+            });
+            let oninput_max = Callback::from(move |event: InputEvent| {
+                on_max(event.target_unchecked_into::<HtmlInputElement>().value());
+            });
+
+            html! {
+                <div class="table-filter-range">
+                    <input
+                        class="table-filter"
+                        type="text"
+                        inputmode="decimal"
+                        placeholder="Min"
+                        aria-label={format!("{} minimum", label)}
+                        value={min}
+                        oninput={oninput_min}
+                    />
+                    <input
+                        class="table-filter"
+                        type="text"
+                        inputmode="decimal"
+                        placeholder="Max"
+                        aria-label={format!("{} maximum", label)}
+                        value={max}
+                        oninput={oninput_max}
+                    />
+                </div>
+            }
+        }
     }
 }
@@ -0,0 +1,186 @@
+//! Table Selection Module
+//!
+//! This module provides row selection for the `Table` component. Rows are identified by a key
+//! rather than by their position, so a selection survives sorting, filtering and paging. The
+//! functions updating a selection are pure, which keeps the selection rules testable without a
+//! browser.
+
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    rc::Rc,
+};
+
+/// How rows of a `Table` can be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Rows cannot be selected.
+    #[default]
+    None,
+    /// At most one row can be selected at a time.
+    Single,
+    /// Any number of rows can be selected, with a select-all checkbox in the header and
+    /// shift-click to select a range of rows.
+    Multi,
+}
+
+/// Extracts the stable key of a row, such as its database ID.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::selection::RowKey;
+///
+/// struct User {
+///     id: u32,
+/// }
+///
+/// let key = RowKey::new(|user: &User| user.id.to_string());
+/// assert_eq!(key.key(&User { id: 7 }), "7");
+/// ```
+pub struct RowKey<T>(Rc<dyn Fn(&T) -> String>);
+
+impl<T> RowKey<T> {
+    /// Creates a new row key from a function returning the key of a row.
+    pub fn new(key: impl Fn(&T) -> String + 'static) -> Self {
+        Self(Rc::new(key))
+    }
+
+    /// Returns the key of a row.
+    pub fn key(&self, row: &T) -> String {
+        (self.0)(row)
+    }
+}
+
+impl<T> Clone for RowKey<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for RowKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> Debug for RowKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RowKey").finish_non_exhaustive()
+    }
+}
+
+/// Toggles the selection of a single row.
+///
+/// In `SelectionMode::Single` selecting a row replaces the current selection.
+///
+/// # Parameters
+/// - `selected`: The keys of the selected rows.
+/// - `key`: The key of the clicked row.
+/// - `mode`: The selection mode of the table.
+///
+/// # Returns
+/// - The keys of the selected rows after the click.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::selection::{
+///     toggle_row,
+///     SelectionMode,
+/// };
+///
+/// let selected = vec!["a".to_string()];
+/// assert_eq!(toggle_row(&selected, "b", SelectionMode::Multi), vec!["a", "b"]);
+/// assert_eq!(toggle_row(&selected, "b", SelectionMode::Single), vec!["b"]);
+/// assert!(toggle_row(&selected, "a", SelectionMode::Multi).is_empty());
+/// ```
+pub fn toggle_row(selected: &[String], key: &str, mode: SelectionMode) -> Vec<String> {
+    let is_selected = selected.iter().any(|selected| selected == key);
+
+    match mode {
+        SelectionMode::None => selected.to_vec(),
+        SelectionMode::Single if is_selected => Vec::new(),
+        SelectionMode::Single => vec![key.to_string()],
+        SelectionMode::Multi if is_selected => selected.iter().filter(|selected| *selected != key).cloned().collect(),
+        SelectionMode::Multi => selected.iter().cloned().chain(std::iter::once(key.to_string())).collect(),
+    }
+}
+
+/// Selects every row between the `anchor` row and the clicked row, both included.
+///
+/// The range is taken from the rows in the order they are currently shown. When the anchor is no
+/// longer shown, only the clicked row is selected.
+///
+/// # Parameters
+/// - `selected`: The keys of the selected rows.
+/// - `visible`: The keys of the shown rows, in display order.
+/// - `anchor`: The key of the row clicked before.
+/// - `key`: The key of the clicked row.
+///
+/// # Returns
+/// - The keys of the selected rows after the click.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::selection::select_range;
+///
+/// let visible = ["d", "b", "a", "c"].map(String::from);
+/// assert_eq!(select_range(&[], &visible, "b", "c"), vec!["b", "a", "c"]);
+/// ```
+pub fn select_range(selected: &[String], visible: &[String], anchor: &str, key: &str) -> Vec<String> {
+    let position = |key: &str| visible.iter().position(|visible| visible == key);
+
+    let range = match (position(anchor), position(key)) {
+        (Some(anchor), Some(key)) => &visible[anchor.min(key)..=anchor.max(key)],
+        (None, Some(key)) => &visible[key..=key],
+        _ => &[],
+    };
+
+    let already_selected = selected.iter().map(String::as_str).collect::<HashSet<_>>();
+    let added = range.iter().filter(|key| !already_selected.contains(key.as_str()));
+    selected.iter().chain(added).cloned().collect()
+}
+
+/// Selects all shown rows, or deselects them when they are all selected already.
+///
+/// Rows that are selected but not shown, e.g. because they are on another page, keep their
+/// selection.
+///
+/// # Parameters
+/// - `selected`: The keys of the selected rows.
+/// - `visible`: The keys of the shown rows.
+///
+/// # Returns
+/// - The keys of the selected rows after the click.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::selection::toggle_all;
+///
+/// let visible = ["a", "b"].map(String::from);
+/// let selected = toggle_all(&["z".to_string()], &visible);
+/// assert_eq!(selected, vec!["z", "a", "b"]);
+/// assert_eq!(toggle_all(&selected, &visible), vec!["z"]);
+/// ```
+pub fn toggle_all(selected: &[String], visible: &[String]) -> Vec<String> {
+    if all_selected(selected, visible) {
+        let visible = visible.iter().map(String::as_str).collect::<HashSet<_>>();
+        selected.iter().filter(|key| !visible.contains(key.as_str())).cloned().collect()
+    } else {
+        select_range(
+            selected,
+            visible,
+            visible.first().map_or("", String::as_str),
+            visible.last().map_or("", String::as_str),
+        )
+    }
+}
+
+/// Checks whether there are shown rows and all of them are selected.
+pub fn all_selected(selected: &[String], visible: &[String]) -> bool {
+    let selected = selected.iter().map(String::as_str).collect::<HashSet<_>>();
+    !visible.is_empty() && visible.iter().all(|key| selected.contains(key.as_str()))
+}
@@ -0,0 +1,405 @@
+//! Table Sorting Module
+//!
+//! This module provides the sorting used by the `Table` component. Every column compares its rows
+//! according to a `SortKind`, so numbers, dates and strings containing numbers sort the way a user
+//! expects instead of by their raw bytes. The table is sorted by a stack of `SortKey`s: the first
+//! key decides the order, and every following key breaks the ties left by the keys before it.
+
+use std::cmp::Ordering;
+
+use time::{
+    format_description::well_known::{
+        Iso8601,
+        Rfc3339,
+    },
+    Date,
+    OffsetDateTime,
+    PrimitiveDateTime,
+    UtcOffset,
+};
+
+use super::{
+    column::Column,
+    SortDirection,
+};
+
+/// The way the values of a column are compared when sorting.
+///
+/// Values that cannot be parsed by `SortKind::Numeric` or `SortKind::Date` are placed after all
+/// valid values in ascending order and compared as text among themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortKind {
+    /// Compares the values character by character.
+    #[default]
+    Text,
+    /// Compares the values ignoring upper and lower case.
+    CaseInsensitive,
+    /// Compares the values ignoring case, with runs of digits compared by their numeric value, so
+    /// that `"file9"` sorts before `"file10"`.
+    Natural,
+    /// Compares the values using the browser's locale collation (`String.prototype.localeCompare`).
+    /// Falls back to `SortKind::Natural` outside the browser.
+    Locale,
+    /// Compares the values as numbers.
+    Numeric,
+    /// Compares the values as ISO 8601 / RFC 3339 dates, with or without a time and an offset.
+    Date,
+}
+
+impl SortKind {
+    /// Orders two values in ascending order.
+    ///
+    /// # Parameters
+    /// - `a`: The first value.
+    /// - `b`: The second value.
+    ///
+    /// # Returns
+    /// - The `Ordering` of `a` relative to `b`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    ///
+    /// use zirv_ui::table::sort::SortKind;
+    ///
+    /// assert_eq!(SortKind::Text.compare("10", "9"), Ordering::Less);
+    /// assert_eq!(SortKind::Numeric.compare("10", "9"), Ordering::Greater);
+    /// assert_eq!(SortKind::Natural.compare("Item 9", "item 10"), Ordering::Less);
+    /// assert_eq!(SortKind::CaseInsensitive.compare("apple", "Banana"), Ordering::Less);
+    /// assert_eq!(SortKind::Date.compare("2024-02-01", "2024-01-31T23:00:00Z"), Ordering::Greater);
+    /// assert_eq!(SortKind::Numeric.compare("n/a", "3"), Ordering::Greater);
+    /// ```
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.sort_value(a.to_string()).compare(&self.sort_value(b.to_string()))
+    }
+
+    /// Prepares a value for comparison, parsing or folding it once so that sorting many rows does
+    /// not repeat the work for every comparison.
+    pub(crate) fn sort_value(&self, value: String) -> SortValue {
+        match self {
+            SortKind::Text => SortValue::Text(value),
+            SortKind::CaseInsensitive => SortValue::CaseInsensitive { folded: value.to_lowercase(), value },
+            SortKind::Natural => SortValue::Natural { folded: value.to_lowercase(), value },
+            SortKind::Locale => SortValue::Locale(value),
+            SortKind::Numeric => SortValue::Numeric(parse_number(&value), value),
+            SortKind::Date => SortValue::Date(parse_date(&value), value),
+        }
+    }
+}
+
+/// A value of a column prepared for comparison by `SortKind::sort_value`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SortValue {
+    Text(String),
+    CaseInsensitive { folded: String, value: String },
+    Natural { folded: String, value: String },
+    Locale(String),
+    Numeric(Option<f64>, String),
+    Date(Option<PrimitiveDateTime>, String),
+}
+
+impl SortValue {
+    /// Orders two values of the same `SortKind` in ascending order.
+    pub(crate) fn compare(&self, other: &SortValue) -> Ordering {
+        match (self, other) {
+            (SortValue::Text(a), SortValue::Text(b)) => a.cmp(b),
+            (
+                SortValue::CaseInsensitive { folded: a_folded, value: a },
+                SortValue::CaseInsensitive { folded: b_folded, value: b },
+            ) => a_folded.cmp(b_folded).then_with(|| a.cmp(b)),
+            (SortValue::Natural { folded: a_folded, value: a }, SortValue::Natural { folded: b_folded, value: b }) => {
+                natural_folded_cmp(a_folded, b_folded).then_with(|| a.cmp(b))
+            }
+            (SortValue::Locale(a), SortValue::Locale(b)) => locale_cmp(a, b),
+            (SortValue::Numeric(a_number, a), SortValue::Numeric(b_number, b)) => {
+                parsed_cmp(a_number, b_number, a, b, |a, b| a.total_cmp(b))
+            }
+            (SortValue::Date(a_date, a), SortValue::Date(b_date, b)) => parsed_cmp(a_date, b_date, a, b, Ord::cmp),
+            (a, b) => a.text().cmp(b.text()),
+        }
+    }
+
+    /// Returns the original text of the value.
+    fn text(&self) -> &str {
+        match self {
+            SortValue::Text(value)
+            | SortValue::CaseInsensitive { value, .. }
+            | SortValue::Natural { value, .. }
+            | SortValue::Locale(value)
+            | SortValue::Numeric(_, value)
+            | SortValue::Date(_, value) => value,
+        }
+    }
+}
+
+/// Where the rows of a `Table` are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortMode {
+    /// The table sorts its rows itself.
+    #[default]
+    Client,
+    /// The rows are sorted by the server and rendered in the order they are given. Clicking a
+    /// header only updates the sort state and emits the sort callbacks, so the new order can be
+    /// requested.
+    Server,
+}
+
+/// A column the table is sorted by, together with its direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    /// The index of the column.
+    pub column: usize,
+    /// The direction the column is sorted in.
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    /// Creates a new sort key.
+    pub fn new(column: usize, direction: SortDirection) -> Self {
+        Self { column, direction }
+    }
+}
+
+/// Updates a sort stack after the header of a column was clicked.
+///
+/// The direction of the column moves on to the next direction (ascending, descending, unsorted).
+/// A plain click makes the column the only sort key, while a click that extends the sort (a
+/// shift-click) keeps the other keys, appending the column if it was not sorted yet and removing
+/// it once it becomes unsorted.
+///
+/// # Parameters
+/// - `stack`: The current sort stack.
+/// - `column`: The index of the clicked column.
+/// - `extend`: Whether to keep the other columns of the stack.
+///
+/// # Returns
+/// - The new sort stack.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::{
+///     sort::{
+///         toggle_sort,
+///         SortKey,
+///     },
+///     SortDirection,
+/// };
+///
+/// let stack = toggle_sort(&[], 0, false);
+/// let stack = toggle_sort(&stack, 2, true);
+/// assert_eq!(
+///     stack,
+///     vec![SortKey::new(0, SortDirection::Ascending), SortKey::new(2, SortDirection::Ascending)]
+/// );
+///
+/// let stack = toggle_sort(&stack, 0, false);
+/// assert_eq!(stack, vec![SortKey::new(0, SortDirection::Descending)]);
+/// ```
+pub fn toggle_sort(stack: &[SortKey], column: usize, extend: bool) -> Vec<SortKey> {
+    let position = stack.iter().position(|key| key.column == column);
+    let direction = position.map_or(SortDirection::None, |index| stack[index].direction).next();
+
+    if !extend {
+        return match direction {
+            SortDirection::None => Vec::new(),
+            direction => vec![SortKey::new(column, direction)],
+        };
+    }
+
+    let mut stack = stack.to_vec();
+    match (position, direction) {
+        (Some(index), SortDirection::None) => {
+            stack.remove(index);
+        }
+        (Some(index), direction) => stack[index].direction = direction,
+        (None, direction) => stack.push(SortKey::new(column, direction)),
+    }
+    stack
+}
+
+/// Sorts rows by a sort stack.
+///
+/// The sort is stable, so rows that compare equal on every key keep their original order. Keys
+/// referring to a column that does not exist are ignored.
+///
+/// # Parameters
+/// - `rows`: The rows to sort.
+/// - `columns`: The columns of the table.
+/// - `stack`: The sort stack, most significant key first.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::table::{
+///     column::Column,
+///     sort::{
+///         sort_rows,
+///         SortKey,
+///         SortKind,
+///     },
+///     SortDirection,
+/// };
+///
+/// let data = vec![
+///     vec!["b".to_string(), "10".to_string()],
+///     vec!["a".to_string(), "9".to_string()],
+///     vec!["b".to_string(), "9".to_string()],
+///     vec!["c".to_string()],
+/// ];
+/// let columns =
+///     vec![Column::index("Name", 0), Column::index("Count", 1).with_sort_kind(SortKind::Numeric)];
+///
+/// let mut rows = data.iter().collect::<Vec<_>>();
+/// sort_rows(
+///     &mut rows,
+///     &columns,
+///     &[SortKey::new(0, SortDirection::Descending), SortKey::new(1, SortDirection::Ascending)],
+/// );
+///
+/// let order = rows.iter().map(|row| row.join(" ")).collect::<Vec<_>>();
+/// assert_eq!(order, vec!["c", "b 9", "b 10", "a 9"]);
+/// ```
+pub fn sort_rows<T>(rows: &mut [&T], columns: &[Column<T>], stack: &[SortKey]) {
+    let mut sorted = rows.to_vec();
+    sort_rows_by(&mut sorted, columns, stack, |row| *row);
+    rows.copy_from_slice(&sorted);
+}
+
+/// Sorts items that each hold a row, such as rows paired with their key, by a sort stack.
+///
+/// The value of every row in every sorted column is extracted and parsed once up front, so the
+/// comparisons themselves do not allocate.
+pub(crate) fn sort_rows_by<R, T>(items: &mut Vec<R>, columns: &[Column<T>], stack: &[SortKey], row: impl Fn(&R) -> &T) {
+    let keys = stack
+        .iter()
+        .filter(|key| key.direction != SortDirection::None)
+        .filter_map(|key| columns.get(key.column).map(|column| (column, key.direction)))
+        .collect::<Vec<_>>();
+
+    if keys.is_empty() {
+        return;
+    }
+
+    let mut keyed = items
+        .drain(..)
+        .map(|item| {
+            let values = keys.iter().map(|(column, _)| column.sort_value(row(&item))).collect::<Vec<_>>();
+            (values, item)
+        })
+        .collect::<Vec<_>>();
+
+    keyed.sort_by(|(a_values, a), (b_values, b)| {
+        keys.iter()
+            .zip(a_values.iter().zip(b_values))
+            .map(|((column, direction), values)| {
+                let ordering = match values {
+                    (Some(a_value), Some(b_value)) => a_value.compare(b_value),
+                    // Columns with a comparator compare the rows themselves
+                    _ => column.compare(row(a), row(b)),
+                };
+                direction.apply(ordering)
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    items.extend(keyed.into_iter().map(|(_, item)| item));
+}
+
+/// Compares two values by their parsed form, placing values that cannot be parsed last.
+fn parsed_cmp<V>(a: &Option<V>, b: &Option<V>, a_text: &str, b_text: &str, cmp: fn(&V, &V) -> Ordering) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => cmp(a, b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => natural_cmp(a_text, b_text),
+    }
+}
+
+/// Parses a number, ignoring surrounding whitespace.
+fn parse_number(value: &str) -> Option<f64> {
+    value.trim().parse::<f64>().ok().filter(|number| !number.is_nan())
+}
+
+/// Parses an ISO 8601 date or date and time into a UTC date and time.
+///
+/// Dates and times without an offset are treated as UTC, and a space may separate the date from
+/// the time.
+fn parse_date(value: &str) -> Option<PrimitiveDateTime> {
+    let value = value.trim().replacen(' ', "T", 1);
+
+    if let Ok(date_time) = OffsetDateTime::parse(&value, &Rfc3339) {
+        let utc = date_time.to_offset(UtcOffset::UTC);
+        return Some(PrimitiveDateTime::new(utc.date(), utc.time()));
+    }
+
+    PrimitiveDateTime::parse(&value, &Iso8601::DEFAULT)
+        .ok()
+        .or_else(|| Date::parse(&value, &Iso8601::DEFAULT).ok().map(Date::midnight))
+}
+
+/// Compares two strings ignoring case, with runs of ASCII digits compared by their numeric value.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    natural_folded_cmp(&a.to_lowercase(), &b.to_lowercase()).then_with(|| a.cmp(b))
+}
+
+/// Compares two lowercase strings with runs of ASCII digits compared by their numeric value.
+fn natural_folded_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chunks = chunks(a);
+    let mut b_chunks = chunks(b);
+
+    loop {
+        let ordering = match (a_chunks.next(), b_chunks.next()) {
+            (Some(a_chunk), Some(b_chunk)) => chunk_cmp(a_chunk, b_chunk),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (None, None) => return Ordering::Equal,
+        };
+
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+/// Compares two chunks of `natural_folded_cmp`.
+fn chunk_cmp(a: &str, b: &str) -> Ordering {
+    let is_number = |chunk: &str| chunk.starts_with(|c: char| c.is_ascii_digit());
+
+    if is_number(a) && is_number(b) {
+        // Compare without leading zeros: the longer number is larger, equal lengths compare by digit
+        let a = a.trim_start_matches('0');
+        let b = b.trim_start_matches('0');
+        a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+    } else {
+        a.cmp(b)
+    }
+}
+
+/// Splits a string into alternating runs of ASCII digits and other characters.
+fn chunks(value: &str) -> impl Iterator<Item = &str> {
+    let mut rest = value;
+
+    std::iter::from_fn(move || {
+        let is_digit = rest.chars().next()?.is_ascii_digit();
+        let end = rest.find(|c: char| c.is_ascii_digit() != is_digit).unwrap_or(rest.len());
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+        Some(chunk)
+    })
+}
+
+/// Compares two strings with the collation of the browser's locale.
+#[cfg(target_arch = "wasm32")]
+fn locale_cmp(a: &str, b: &str) -> Ordering {
+    let result = js_sys::JsString::from(a).locale_compare(b, &js_sys::Array::new(), &js_sys::Object::new());
+    result.cmp(&0).then_with(|| a.cmp(b))
+}
+
+/// Compares two strings with the collation of the browser's locale.
+#[cfg(not(target_arch = "wasm32"))]
+fn locale_cmp(a: &str, b: &str) -> Ordering {
+    natural_cmp(a, b)
+}
@@ -0,0 +1,326 @@
+//! Theme Builder Module
+//!
+//! This module provides the `ThemeBuilder`, which collects the colors of a `Theme` and validates
+//! them all when the theme is built. Invalid colors are reported as a `ThemeError` naming the
+//! field and the rejected input, where the `set_*` methods of `Theme` log and ignore them.
+
+use std::fmt::{
+    self,
+    Display,
+};
+
+use super::Theme;
+
+/// A color field of a `Theme`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeField {
+    BackgroundColor,
+    ModuleColor,
+    TextColorPrimary,
+    TextColorSecondary,
+    PrimaryColor,
+    SecondaryColor,
+    TertiaryColor,
+    SuccessColor,
+    WarningColor,
+    ErrorColor,
+    DisabledColor,
+}
+
+impl ThemeField {
+    /// Returns the name of the field, as used by the `Theme` struct.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ThemeField::BackgroundColor => "background_color",
+            ThemeField::ModuleColor => "module_color",
+            ThemeField::TextColorPrimary => "text_color_primary",
+            ThemeField::TextColorSecondary => "text_color_secondary",
+            ThemeField::PrimaryColor => "primary_color",
+            ThemeField::SecondaryColor => "secondary_color",
+            ThemeField::TertiaryColor => "tertiary_color",
+            ThemeField::SuccessColor => "success_color",
+            ThemeField::WarningColor => "warning_color",
+            ThemeField::ErrorColor => "error_color",
+            ThemeField::DisabledColor => "disabled_color",
+        }
+    }
+}
+
+impl Display for ThemeField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An error building a `Theme`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeError {
+    /// A color could not be parsed.
+    InvalidColor {
+        /// The field the color was given for.
+        field: ThemeField,
+        /// The rejected input.
+        input: String,
+        /// Why the input was rejected.
+        reason: String,
+    },
+}
+
+impl ThemeError {
+    /// Returns the field the error occurred in.
+    pub fn field(&self) -> ThemeField {
+        match self {
+            ThemeError::InvalidColor { field, .. } => *field,
+        }
+    }
+
+    /// Returns the rejected input.
+    pub fn input(&self) -> &str {
+        match self {
+            ThemeError::InvalidColor { input, .. } => input,
+        }
+    }
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::InvalidColor { field, input, reason } => {
+                write!(f, "invalid color {:?} for `{}`: {}", input, field, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+/// Builds a `Theme`, validating every color.
+///
+/// Colors are accepted in any CSS notation, such as `"rgb(44,105,141)"`, `"#2c698d"` or
+/// `"steelblue"`. Fields that are not set keep the color of the base theme.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::theme::builder::{
+///     ThemeBuilder,
+///     ThemeError,
+///     ThemeField,
+/// };
+///
+/// let theme = ThemeBuilder::new().with_primary_color("#ff0000").build().unwrap();
+/// assert_eq!(theme.primary_color, "#ff0000");
+///
+/// let error = ThemeBuilder::new()
+///     .with_background_color("rgb(0,0,0)")
+///     .with_primary_color("rgb(255,0")
+///     .build()
+///     .unwrap_err();
+/// assert_eq!(error.field(), ThemeField::PrimaryColor);
+/// assert_eq!(error.input(), "rgb(255,0");
+/// assert!(matches!(error, ThemeError::InvalidColor { .. }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ThemeBuilder {
+    base: Theme,
+    colors: Vec<(ThemeField, String)>,
+}
+
+impl ThemeBuilder {
+    /// Creates a new builder starting from the default theme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new builder starting from an existing theme.
+    ///
+    /// # Parameters
+    /// - `theme`: The theme whose colors are kept for the fields that are not set.
+    pub fn from_theme(theme: Theme) -> Self {
+        Self { base: theme, colors: Vec::new() }
+    }
+
+    /// Sets the color of a field.
+    ///
+    /// # Parameters
+    /// - `field`: The field to set.
+    /// - `color`: The color, validated when the theme is built.
+    pub fn with_color(mut self, field: ThemeField, color: impl Into<String>) -> Self {
+        self.colors.push((field, color.into()));
+        self
+    }
+
+    /// Sets the background color and its variants.
+    pub fn with_background_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::BackgroundColor, color)
+    }
+
+    /// Sets the module color and its variants.
+    pub fn with_module_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::ModuleColor, color)
+    }
+
+    /// Sets the primary text color.
+    pub fn with_text_color_primary(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::TextColorPrimary, color)
+    }
+
+    /// Sets the secondary text color.
+    pub fn with_text_color_secondary(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::TextColorSecondary, color)
+    }
+
+    /// Sets the primary color and its variants.
+    pub fn with_primary_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::PrimaryColor, color)
+    }
+
+    /// Sets the secondary color and its variants.
+    pub fn with_secondary_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::SecondaryColor, color)
+    }
+
+    /// Sets the tertiary color and its variants.
+    pub fn with_tertiary_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::TertiaryColor, color)
+    }
+
+    /// Sets the success color and its variants.
+    pub fn with_success_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::SuccessColor, color)
+    }
+
+    /// Sets the warning color and its variants.
+    pub fn with_warning_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::WarningColor, color)
+    }
+
+    /// Sets the error color and its variants.
+    pub fn with_error_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::ErrorColor, color)
+    }
+
+    /// Sets the disabled color and its variants.
+    pub fn with_disabled_color(self, color: impl Into<String>) -> Self {
+        self.with_color(ThemeField::DisabledColor, color)
+    }
+
+    /// Builds the theme.
+    ///
+    /// # Returns
+    /// - The theme, or a `ThemeError` for the first color that could not be parsed.
+    pub fn build(self) -> Result<Theme, ThemeError> {
+        self.colors.iter().try_fold(self.base, |theme, (field, color)| theme.try_set_color(*field, color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELDS: [ThemeField; 11] = [
+        ThemeField::BackgroundColor,
+        ThemeField::ModuleColor,
+        ThemeField::TextColorPrimary,
+        ThemeField::TextColorSecondary,
+        ThemeField::PrimaryColor,
+        ThemeField::SecondaryColor,
+        ThemeField::TertiaryColor,
+        ThemeField::SuccessColor,
+        ThemeField::WarningColor,
+        ThemeField::ErrorColor,
+        ThemeField::DisabledColor,
+    ];
+
+    #[test]
+    fn build_accepts_valid_colors() {
+        let theme = FIELDS
+            .iter()
+            .fold(ThemeBuilder::new(), |builder, field| builder.with_color(*field, "#336699"))
+            .build()
+            .unwrap();
+
+        assert_eq!(theme.primary_color, "#336699");
+        assert_eq!(theme.text_color_secondary, "#336699");
+        assert_ne!(theme.primary_color_dark, theme.primary_color_light);
+    }
+
+    #[test]
+    fn build_reports_the_field_and_input_of_an_invalid_color() {
+        for field in FIELDS {
+            let error = ThemeBuilder::new().with_color(field, "rgb(255,0").build().unwrap_err();
+
+            assert_eq!(error.field(), field);
+            assert_eq!(error.input(), "rgb(255,0");
+            assert!(error.to_string().contains(field.name()));
+        }
+    }
+
+    #[test]
+    fn build_reports_the_first_invalid_color() {
+        let error = ThemeBuilder::new()
+            .with_primary_color("#fff")
+            .with_warning_color("not a color")
+            .with_error_color("#12")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(error.field(), ThemeField::WarningColor);
+        assert_eq!(error.input(), "not a color");
+    }
+
+    #[test]
+    fn try_set_color_reports_the_field_and_input_of_an_invalid_color() {
+        for field in FIELDS {
+            let error = Theme::default().try_set_color(field, "#ggg").unwrap_err();
+
+            assert_eq!(error.field(), field);
+            assert_eq!(error.input(), "#ggg");
+        }
+    }
+
+    #[test]
+    fn try_set_color_sets_the_color_and_its_variants() {
+        let theme = Theme::default().try_set_color(ThemeField::ErrorColor, "rgb(200,0,0)").unwrap();
+
+        assert_eq!(theme.error_color, "rgb(200,0,0)");
+        assert_ne!(theme.error_color_dark, Theme::default().error_color_dark);
+        assert_ne!(theme.error_color_light, Theme::default().error_color_light);
+    }
+
+    #[test]
+    fn text_colors_accept_css_variables_and_keywords() {
+        for color in ["var(--brand)", "inherit", "currentColor", "rgb(33,37,41)"] {
+            let theme = Theme::default().try_set_color(ThemeField::TextColorPrimary, color).unwrap();
+
+            assert_eq!(theme.text_color_primary, color);
+        }
+    }
+
+    #[test]
+    fn setters_keep_the_previous_color_when_it_is_invalid() {
+        let theme = Theme::default()
+            .set_primary_color("rgb(255,0")
+            .set_error_color("#ggg")
+            .set_text_color_secondary("not a color");
+
+        assert_eq!(theme, Theme::default());
+    }
+
+    #[test]
+    fn setters_apply_valid_colors_after_invalid_ones() {
+        let theme = Theme::default().set_primary_color("rgb(255,0").set_primary_color("#000");
+
+        assert_eq!(theme.primary_color, "#000");
+        assert_eq!(theme, Theme::default().try_set_color(ThemeField::PrimaryColor, "#000").unwrap());
+    }
+
+    #[test]
+    fn from_theme_keeps_the_fields_that_are_not_set() {
+        let base = Theme::default().set_text_color_primary("inherit");
+        let theme = ThemeBuilder::from_theme(base).with_primary_color("#000").build().unwrap();
+
+        assert_eq!(theme.text_color_primary, "inherit");
+        assert_eq!(theme.primary_color, "#000");
+    }
+}
@@ -0,0 +1,114 @@
+//! Theme Contrast Module
+//!
+//! This module provides the WCAG 2.x contrast calculations behind `Theme`: the relative luminance
+//! of a color, the contrast ratio between two colors, the choice of a readable text color on a
+//! colored background, and the `ContrastIssue`s reported by `Theme::audit`.
+
+use csscolorparser::Color;
+
+/// The minimum contrast ratio of normal text to its background (WCAG 2.x level AA).
+pub const MIN_TEXT_CONTRAST: f64 = 4.5;
+
+/// The minimum contrast ratio of large text and graphical elements such as icons to their
+/// background (WCAG 2.x level AA).
+pub const MIN_LARGE_TEXT_CONTRAST: f64 = 3.0;
+
+/// The text color used on backgrounds that are too light for white text.
+pub const DARK_TEXT: &str = "rgb(0,0,0)";
+
+/// The text color used on backgrounds that are too dark for black text.
+pub const LIGHT_TEXT: &str = "rgb(255,255,255)";
+
+/// Computes the relative luminance of a color as defined by WCAG 2.x.
+///
+/// # Parameters
+/// - `color`: The color. Its opacity is ignored.
+///
+/// # Returns
+/// - The luminance, from `0.0` for black to `1.0` for white.
+pub fn relative_luminance(color: &Color) -> f64 {
+    let channel = |value: f64| {
+        let value = value.clamp(0.0, 1.0);
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+}
+
+/// Computes the WCAG 2.x contrast ratio between two colors.
+///
+/// # Parameters
+/// - `a`: The first color.
+/// - `b`: The second color.
+///
+/// # Returns
+/// - The ratio, from `1.0` for identical colors to `21.0` for black and white.
+///
+/// # Example
+///
+/// ```rust
+/// use csscolorparser::Color;
+/// use zirv_ui::theme::contrast::contrast_ratio;
+///
+/// let white = Color::from_html("white").unwrap();
+/// let black = Color::from_html("black").unwrap();
+/// let gray = Color::from_html("#777").unwrap();
+///
+/// assert_eq!(contrast_ratio(&white, &black), 21.0);
+/// assert_eq!(contrast_ratio(&gray, &gray), 1.0);
+/// assert!((contrast_ratio(&white, &gray) - 4.48).abs() < 0.01);
+/// ```
+pub fn contrast_ratio(a: &Color, b: &Color) -> f64 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Chooses the text color with the higher contrast on a background: `LIGHT_TEXT` or `DARK_TEXT`.
+///
+/// # Parameters
+/// - `background`: The background the text is shown on.
+///
+/// # Returns
+/// - `LIGHT_TEXT` or `DARK_TEXT`.
+///
+/// # Example
+///
+/// ```rust
+/// use csscolorparser::Color;
+/// use zirv_ui::theme::contrast::{
+///     on_color,
+///     DARK_TEXT,
+///     LIGHT_TEXT,
+/// };
+///
+/// assert_eq!(on_color(&Color::from_html("rgb(44,105,141)").unwrap()), LIGHT_TEXT);
+/// assert_eq!(on_color(&Color::from_html("rgb(186,232,232)").unwrap()), DARK_TEXT);
+/// ```
+pub fn on_color(background: &Color) -> &'static str {
+    let contrast = |text: &str| Color::from_html(text).map_or(0.0, |text| contrast_ratio(&text, background));
+
+    if contrast(LIGHT_TEXT) >= contrast(DARK_TEXT) {
+        LIGHT_TEXT
+    } else {
+        DARK_TEXT
+    }
+}
+
+/// A pair of theme colors used together by a built-in component whose contrast is too low.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    /// Where the colors are used together, e.g. `"Button"`.
+    pub usage: &'static str,
+    /// The name of the foreground field of the theme, e.g. `"on_primary_color"`.
+    pub foreground: &'static str,
+    /// The name of the background field of the theme, e.g. `"primary_color"`.
+    pub background: &'static str,
+    /// The contrast ratio of the two colors.
+    pub ratio: f64,
+    /// The minimum contrast ratio required for this usage.
+    pub required: f64,
+}
@@ -2,42 +2,129 @@
 //!
 //! This module provides a theme system for styling applications, including a `Theme` struct,
 //! utility functions for color manipulation, and a `ThemeProvider` component for Yew applications.
+//! The provider can switch between a light and a dark theme, following the system preference
+//! unless the user picked a mode through the `use_theme` hook.
+
+/// Validating construction of themes
+pub mod builder;
+
+/// WCAG contrast ratios and readable text colors
+pub mod contrast;
+
+/// The light, dark and system modes and their persistence
+pub mod mode;
+
+/// Perceptual shade generation for theme colors
+pub mod shades;
+
+/// Hook for reading and changing the theme mode
+pub mod use_theme;
 
 use csscolorparser::Color;
+use gloo::events::EventListener;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::MediaQueryList;
 use yew::{
+    classes,
     function_component,
     html,
+    use_callback,
+    use_context,
+    use_effect_with,
+    use_memo,
+    use_state,
+    use_state_eq,
     Children,
+    ContextProvider,
     Html,
     Properties,
 };
 
+use self::{
+    builder::{
+        ThemeError,
+        ThemeField,
+    },
+    contrast::{
+        ContrastIssue,
+        MIN_LARGE_TEXT_CONTRAST,
+        MIN_TEXT_CONTRAST,
+    },
+    mode::{
+        dark_scheme_query,
+        load_mode,
+        store_mode,
+        ThemeMode,
+    },
+    use_theme::ThemeState,
+};
+use crate::options::color::Palette;
+
+/// The `localStorage` key the mode chosen by the user is persisted under by default.
+pub const DEFAULT_STORAGE_KEY: &str = "zirv-ui-theme-mode";
+
 /// Creates dark and light variants of a given color
 ///
+/// The variants differ from the color in perceived lightness only, keeping its hue, so dark colors
+/// get a visibly darker variant and white still gets a distinct dark variant.
+///
 /// # Arguments
 ///
+/// * `field` - The theme field the color is given for, named in the error
 /// * `rgb` - A string representation of an RGB color (e.g., "rgb(255,0,0)")
 ///
 /// # Returns
 ///
-/// A tuple containing the original color, a darker variant, and a lighter variant
-fn create_color_variants(rgb: &str) -> (String, String, String) {
-    let color = Color::from_html(rgb).unwrap();
-    let [r, g, b, a] = color.to_rgba8();
-
-    let dark = Color::from_rgba8((r as f32 * 0.9) as u8, (g as f32 * 0.9) as u8, (b as f32 * 0.9) as u8, a);
-    let light = Color::from_rgba8(
-        (r as f32 * 1.1).min(255.0) as u8,
-        (g as f32 * 1.1).min(255.0) as u8,
-        (b as f32 * 1.1).min(255.0) as u8,
-        a,
-    );
+/// A tuple containing the original color, a darker variant, and a lighter variant, or a
+/// `ThemeError` if the color cannot be parsed
+fn create_color_variants(field: ThemeField, rgb: &str) -> Result<(String, String, String), ThemeError> {
+    let color = parse_color(field, rgb)?;
+    let (dark, light) = shades::variants(&color);
+
+    Ok((rgb.to_string(), dark.to_rgb_string(), light.to_rgb_string()))
+}
+
+/// Parses a CSS color given for a theme field
+fn parse_color(field: ThemeField, input: &str) -> Result<Color, ThemeError> {
+    Color::from_html(input).map_err(|error| ThemeError::InvalidColor {
+        field,
+        input: input.to_string(),
+        reason: error.to_string(),
+    })
+}
+
+/// Validates a text color given for a theme field
+///
+/// Text colors have no variants, so besides colors they accept CSS variables such as
+/// `"var(--brand)"` and the keywords `currentColor`, `inherit`, `initial`, `unset` and `revert`.
+fn parse_text_color(field: ThemeField, input: &str) -> Result<(), ThemeError> {
+    let value = input.trim();
+    let is_variable = value.starts_with("var(") && value.ends_with(')');
+    let is_keyword = ["currentcolor", "inherit", "initial", "unset", "revert"]
+        .iter()
+        .any(|keyword| value.eq_ignore_ascii_case(keyword));
 
-    (rgb.to_string(), dark.to_rgb_string(), light.to_rgb_string())
+    if is_variable || is_keyword {
+        Ok(())
+    } else {
+        parse_color(field, input).map(drop)
+    }
+}
+
+/// Chooses the text color shown on a background color of the theme
+///
+/// Colors that cannot be parsed get white text, matching the default theme.
+fn derive_on_color(background: &str) -> String {
+    Color::from_html(background).map_or(contrast::LIGHT_TEXT, |color| contrast::on_color(&color)).to_string()
 }
 
 /// Represents a complete theme with various color properties
-#[derive(Clone, PartialEq)]
+///
+/// `on_primary_color` and `on_secondary_color` are the text colors shown on the primary and
+/// secondary colors. They are derived whenever those colors are set, choosing white or black
+/// text by contrast.
+#[derive(Clone, PartialEq, Debug)]
 pub struct Theme {
     pub background_color: String,
     pub background_color_dark: String,
@@ -47,6 +134,8 @@ pub struct Theme {
     pub module_color_light: String,
     pub text_color_primary: String,
     pub text_color_secondary: String,
+    pub on_primary_color: String,
+    pub on_secondary_color: String,
     pub primary_color: String,
     pub primary_color_dark: String,
     pub primary_color_light: String,
@@ -70,18 +159,23 @@ pub struct Theme {
     pub disabled_color_light: String,
 }
 
+/// Creates the variants of a predefined color of the default theme
+fn default_variants(rgb: &str) -> (String, String, String) {
+    create_color_variants(ThemeField::BackgroundColor, rgb).expect("the default theme colors are valid")
+}
+
 /// Provides a default theme with predefined colors
 impl Default for Theme {
     fn default() -> Self {
-        let (background_color, background_color_dark, background_color_light) = create_color_variants("rgb(37,46,66)");
-        let (module_color, module_color_dark, module_color_light) = create_color_variants("rgb(53,65,90)");
-        let (primary_color, primary_color_dark, primary_color_light) = create_color_variants("rgb(44,105,141)");
-        let (secondary_color, secondary_color_dark, secondary_color_light) = create_color_variants("rgb(186,232,232)");
-        let (tertiary_color, tertiary_color_dark, tertiary_color_light) = create_color_variants("rgb(227,246,245)");
-        let (success_color, success_color_dark, success_color_light) = create_color_variants("rgb(46,204,113)");
-        let (warning_color, warning_color_dark, warning_color_light) = create_color_variants("rgb(243,156,18)");
-        let (error_color, error_color_dark, error_color_light) = create_color_variants("rgb(231,76,60)");
-        let (disabled_color, disabled_color_dark, disabled_color_light) = create_color_variants("rgb(127,140,141)");
+        let (background_color, background_color_dark, background_color_light) = default_variants("rgb(37,46,66)");
+        let (module_color, module_color_dark, module_color_light) = default_variants("rgb(53,65,90)");
+        let (primary_color, primary_color_dark, primary_color_light) = default_variants("rgb(44,105,141)");
+        let (secondary_color, secondary_color_dark, secondary_color_light) = default_variants("rgb(186,232,232)");
+        let (tertiary_color, tertiary_color_dark, tertiary_color_light) = default_variants("rgb(227,246,245)");
+        let (success_color, success_color_dark, success_color_light) = default_variants("rgb(46,204,113)");
+        let (warning_color, warning_color_dark, warning_color_light) = default_variants("rgb(243,156,18)");
+        let (error_color, error_color_dark, error_color_light) = default_variants("rgb(238,96,82)");
+        let (disabled_color, disabled_color_dark, disabled_color_light) = default_variants("rgb(127,140,141)");
 
         Theme {
             background_color,
@@ -92,6 +186,8 @@ impl Default for Theme {
             module_color_light,
             text_color_primary: "rgb(255,255,255)".to_string(),
             text_color_secondary: "rgb(204,204,204)".to_string(),
+            on_primary_color: derive_on_color(&primary_color),
+            on_secondary_color: derive_on_color(&secondary_color),
             primary_color,
             primary_color_dark,
             primary_color_light,
@@ -118,105 +214,471 @@ impl Default for Theme {
 }
 
 impl Theme {
-    /// Sets the background color and its variants
-    pub fn set_background_color(mut self, color: &str) -> Self {
-        let (background_color, background_color_dark, background_color_light) = create_color_variants(color);
-        self.background_color = background_color;
-        self.background_color_dark = background_color_dark;
-        self.background_color_light = background_color_light;
+    /// Returns a `ThemeBuilder` starting from the default theme, validating every color
+    pub fn builder() -> builder::ThemeBuilder {
+        builder::ThemeBuilder::new()
+    }
+
+    /// Sets the color of a field and its variants, if the color can be parsed
+    ///
+    /// Setting the primary or secondary color also derives the text color shown on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The field to set
+    /// * `color` - The color in any CSS notation
+    ///
+    /// # Returns
+    ///
+    /// The updated theme, or a `ThemeError` naming the field and the color
+    pub fn try_set_color(mut self, field: ThemeField, color: &str) -> Result<Self, ThemeError> {
+        self.apply_color(field, color)?;
+        Ok(self)
+    }
+
+    /// Sets the color of a field and its variants in place, leaving the theme unchanged if the
+    /// color cannot be parsed
+    fn apply_color(&mut self, field: ThemeField, color: &str) -> Result<(), ThemeError> {
+        let (main, dark, light) = match field {
+            ThemeField::TextColorPrimary => {
+                parse_text_color(field, color)?;
+                self.text_color_primary = color.to_string();
+                return Ok(());
+            }
+            ThemeField::TextColorSecondary => {
+                parse_text_color(field, color)?;
+                self.text_color_secondary = color.to_string();
+                return Ok(());
+            }
+            ThemeField::BackgroundColor => {
+                (&mut self.background_color, &mut self.background_color_dark, &mut self.background_color_light)
+            }
+            ThemeField::ModuleColor => {
+                (&mut self.module_color, &mut self.module_color_dark, &mut self.module_color_light)
+            }
+            ThemeField::PrimaryColor => {
+                (&mut self.primary_color, &mut self.primary_color_dark, &mut self.primary_color_light)
+            }
+            ThemeField::SecondaryColor => {
+                (&mut self.secondary_color, &mut self.secondary_color_dark, &mut self.secondary_color_light)
+            }
+            ThemeField::TertiaryColor => {
+                (&mut self.tertiary_color, &mut self.tertiary_color_dark, &mut self.tertiary_color_light)
+            }
+            ThemeField::SuccessColor => {
+                (&mut self.success_color, &mut self.success_color_dark, &mut self.success_color_light)
+            }
+            ThemeField::WarningColor => {
+                (&mut self.warning_color, &mut self.warning_color_dark, &mut self.warning_color_light)
+            }
+            ThemeField::ErrorColor => (&mut self.error_color, &mut self.error_color_dark, &mut self.error_color_light),
+            ThemeField::DisabledColor => {
+                (&mut self.disabled_color, &mut self.disabled_color_dark, &mut self.disabled_color_light)
+            }
+        };
+        (*main, *dark, *light) = create_color_variants(field, color)?;
+
+        match field {
+            ThemeField::PrimaryColor => self.on_primary_color = derive_on_color(&self.primary_color),
+            ThemeField::SecondaryColor => self.on_secondary_color = derive_on_color(&self.secondary_color),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Computes the WCAG 2.x contrast ratio between two colors of the theme
+    ///
+    /// # Arguments
+    ///
+    /// * `foreground` - The text or icon color
+    /// * `background` - The color it is shown on
+    ///
+    /// # Returns
+    ///
+    /// The ratio from `1.0` to `21.0`, or `None` if either color cannot be parsed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::theme::Theme;
+    ///
+    /// let theme = Theme::default();
+    /// let ratio = theme.contrast_ratio(&theme.text_color_primary, &theme.background_color).unwrap();
+    /// assert!(ratio > 4.5);
+    /// ```
+    pub fn contrast_ratio(&self, foreground: &str, background: &str) -> Option<f64> {
+        let foreground = Color::from_html(foreground).ok()?;
+        let background = Color::from_html(background).ok()?;
+        Some(contrast::contrast_ratio(&foreground, &background))
+    }
+
+    /// Checks the color pairs used by the built-in components against the WCAG 2.x level AA
+    /// contrast ratios
+    ///
+    /// Text must reach a ratio of 4.5, icons 3.0. Pairs with a color that cannot be parsed are
+    /// skipped.
+    ///
+    /// # Returns
+    ///
+    /// Every pair whose contrast is too low, empty if the theme is accessible
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::theme::Theme;
+    ///
+    /// assert!(Theme::default().audit().is_empty());
+    ///
+    /// // The text shown on the primary color follows it, but the body text does not
+    /// let theme = Theme::default()
+    ///     .set_primary_color("rgb(255,235,59)")
+    ///     .set_background_color("rgb(250,250,250)");
+    /// assert_eq!(theme.on_primary_color, "rgb(0,0,0)");
+    ///
+    /// let issues = theme.audit();
+    /// assert!(issues.iter().all(|issue| issue.foreground != "on_primary_color"));
+    /// assert!(issues.iter().any(|issue| {
+    ///     issue.foreground == "text_color_primary" && issue.background == "background_color"
+    /// }));
+    /// ```
+    pub fn audit(&self) -> Vec<ContrastIssue> {
+        let pairs = [
+            (
+                "Text",
+                "text_color_primary",
+                &self.text_color_primary,
+                "background_color",
+                &self.background_color,
+                MIN_TEXT_CONTRAST,
+            ),
+            (
+                "Secondary text",
+                "text_color_secondary",
+                &self.text_color_secondary,
+                "background_color",
+                &self.background_color,
+                MIN_TEXT_CONTRAST,
+            ),
+            (
+                "Container",
+                "text_color_primary",
+                &self.text_color_primary,
+                "module_color",
+                &self.module_color,
+                MIN_TEXT_CONTRAST,
+            ),
+            (
+                "Toast",
+                "text_color_secondary",
+                &self.text_color_secondary,
+                "module_color",
+                &self.module_color,
+                MIN_TEXT_CONTRAST,
+            ),
+            (
+                "Code block",
+                "text_color_secondary",
+                &self.text_color_secondary,
+                "background_color_dark",
+                &self.background_color_dark,
+                MIN_TEXT_CONTRAST,
+            ),
+            (
+                "Button",
+                "on_primary_color",
+                &self.on_primary_color,
+                "primary_color",
+                &self.primary_color,
+                MIN_TEXT_CONTRAST,
+            ),
+            (
+                "Button",
+                "on_secondary_color",
+                &self.on_secondary_color,
+                "secondary_color",
+                &self.secondary_color,
+                MIN_TEXT_CONTRAST,
+            ),
+            (
+                "Toast icon",
+                "success_color",
+                &self.success_color,
+                "module_color",
+                &self.module_color,
+                MIN_LARGE_TEXT_CONTRAST,
+            ),
+            (
+                "Toast icon",
+                "warning_color",
+                &self.warning_color,
+                "module_color",
+                &self.module_color,
+                MIN_LARGE_TEXT_CONTRAST,
+            ),
+            (
+                "Toast icon",
+                "error_color",
+                &self.error_color,
+                "module_color",
+                &self.module_color,
+                MIN_LARGE_TEXT_CONTRAST,
+            ),
+        ];
+
+        pairs
+            .into_iter()
+            .filter_map(|(usage, foreground, foreground_color, background, background_color, required)| {
+                let ratio = self.contrast_ratio(foreground_color, background_color)?;
+                (ratio < required).then_some(ContrastIssue { usage, foreground, background, ratio, required })
+            })
+            .collect()
+    }
+
+    /// Returns the color of a palette entry
+    pub fn palette_color(&self, palette: Palette) -> &str {
+        match palette {
+            Palette::Primary => &self.primary_color,
+            Palette::Secondary => &self.secondary_color,
+            Palette::Tertiary => &self.tertiary_color,
+            Palette::Container => &self.module_color,
+            Palette::Background => &self.background_color,
+            Palette::Success => &self.success_color,
+            Palette::Warning => &self.warning_color,
+            Palette::Error => &self.error_color,
+            Palette::Disabled => &self.disabled_color,
+        }
+    }
+
+    /// Generates the shades `50` to `900` of every palette color as CSS variable declarations
+    ///
+    /// The variable of a shade is the variable of its palette color followed by the shade, such as
+    /// `--color-primary-500`. Colors that cannot be parsed get no shades.
+    ///
+    /// # Returns
+    ///
+    /// One declaration per line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::theme::Theme;
+    ///
+    /// let variables = Theme::default().shade_variables();
+    /// assert!(variables.contains("--color-primary-50: rgb("));
+    /// assert!(variables.contains("--module-color-900: rgb("));
+    /// ```
+    pub fn shade_variables(&self) -> String {
+        Palette::ALL
+            .iter()
+            .filter_map(|palette| Some((palette, Color::from_html(self.palette_color(*palette)).ok()?)))
+            .flat_map(|(palette, color)| {
+                shades::shade_scale(&color).into_iter().map(move |(shade, color)| {
+                    format!("{}-{}: {};\n", palette.css_variable(), shade.value(), color.to_rgb_string())
+                })
+            })
+            .collect()
+    }
+
+    /// Generates the CSS variable declarations of every color of the theme, including the shades
+    ///
+    /// # Returns
+    ///
+    /// One declaration per line, to be placed in a rule such as `:root { ... }`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::theme::Theme;
+    ///
+    /// let variables = Theme::default().set_primary_color("rgb(255,0,0)").css_variables();
+    /// assert!(variables.contains("--color-primary: rgb(255,0,0);"));
+    /// assert!(variables.contains("--text-color-on-primary: rgb("));
+    /// assert!(variables.contains("--color-primary-500: rgb("));
+    /// ```
+    pub fn css_variables(&self) -> String {
+        format!(
+            r#"
+            --background-color: {};
+            --background-color-dark: {};
+            --background-color-light: {};
+            --module-color: {};
+            --module-color-dark: {};
+            --module-color-light: {};
+            --text-color-primary: {};
+            --text-color-secondary: {};
+            --text-color-on-primary: {};
+            --text-color-on-secondary: {};
+            --color-primary: {};
+            --color-primary-dark: {};
+            --color-primary-light: {};
+            --color-secondary: {};
+            --color-secondary-dark: {};
+            --color-secondary-light: {};
+            --color-tertiery: {};
+            --color-tertiery-dark: {};
+            --color-tertiery-light: {};
+            --color-success: {};
+            --color-success-dark: {};
+            --color-success-light: {};
+            --color-warning: {};
+            --color-warning-dark: {};
+            --color-warning-light: {};
+            --color-error: {};
+            --color-error-dark: {};
+            --color-error-light: {};
+            --color-disabled: {};
+            --color-disabled-dark: {};
+            --color-disabled-light: {};
+            {}
+            "#,
+            self.background_color,
+            self.background_color_dark,
+            self.background_color_light,
+            self.module_color,
+            self.module_color_dark,
+            self.module_color_light,
+            self.text_color_primary,
+            self.text_color_secondary,
+            self.on_primary_color,
+            self.on_secondary_color,
+            self.primary_color,
+            self.primary_color_dark,
+            self.primary_color_light,
+            self.secondary_color,
+            self.secondary_color_dark,
+            self.secondary_color_light,
+            self.tertiary_color,
+            self.tertiary_color_dark,
+            self.tertiary_color_light,
+            self.success_color,
+            self.success_color_dark,
+            self.success_color_light,
+            self.warning_color,
+            self.warning_color_dark,
+            self.warning_color_light,
+            self.error_color,
+            self.error_color_dark,
+            self.error_color_light,
+            self.disabled_color,
+            self.disabled_color_dark,
+            self.disabled_color_light,
+            self.shade_variables(),
+        )
+    }
+
+    /// Sets the color of a field, logging a warning and keeping the previous color if it cannot
+    /// be parsed
+    fn set_color(mut self, field: ThemeField, color: &str) -> Self {
+        if let Err(error) = self.apply_color(field, color) {
+            log::warn!("Ignoring theme color: {}", error);
+        }
         self
     }
 
+    /// Sets the background color and its variants
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_background_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::BackgroundColor, color)
+    }
+
     /// Sets the module color and its variants
-    pub fn set_module_color(mut self, color: &str) -> Self {
-        let (module_color, module_color_dark, module_color_light) = create_color_variants(color);
-        self.module_color = module_color;
-        self.module_color_dark = module_color_dark;
-        self.module_color_light = module_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_module_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::ModuleColor, color)
     }
 
     /// Sets the primary text color
-    pub fn set_text_color_primary(mut self, color: &str) -> Self {
-        self.text_color_primary = color.to_string();
-        self
+    ///
+    /// Besides colors, CSS variables such as `"var(--brand)"` and keywords such as `"inherit"`
+    /// are accepted. An invalid color is logged and ignored. Use `Theme::builder` to handle
+    /// invalid colors.
+    pub fn set_text_color_primary(self, color: &str) -> Self {
+        self.set_color(ThemeField::TextColorPrimary, color)
     }
 
     /// Sets the secondary text color
-    pub fn set_text_color_secondary(mut self, color: &str) -> Self {
-        self.text_color_secondary = color.to_string();
-        self
+    ///
+    /// Besides colors, CSS variables such as `"var(--brand)"` and keywords such as `"inherit"`
+    /// are accepted. An invalid color is logged and ignored. Use `Theme::builder` to handle
+    /// invalid colors.
+    pub fn set_text_color_secondary(self, color: &str) -> Self {
+        self.set_color(ThemeField::TextColorSecondary, color)
     }
 
     /// Sets the primary color and its variants
-    pub fn set_primary_color(mut self, color: &str) -> Self {
-        let (primary_color, primary_color_dark, primary_color_light) = create_color_variants(color);
-        self.primary_color = primary_color;
-        self.primary_color_dark = primary_color_dark;
-        self.primary_color_light = primary_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_primary_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::PrimaryColor, color)
     }
 
     /// Sets the secondary color and its variants
-    pub fn set_secondary_color(mut self, color: &str) -> Self {
-        let (secondary_color, secondary_color_dark, secondary_color_light) = create_color_variants(color);
-        self.secondary_color = secondary_color;
-        self.secondary_color_dark = secondary_color_dark;
-        self.secondary_color_light = secondary_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_secondary_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::SecondaryColor, color)
     }
 
     /// Sets the tertiary color and its variants
-    pub fn set_tertiary_color(mut self, color: &str) -> Self {
-        let (tertiary_color, tertiary_color_dark, tertiary_color_light) = create_color_variants(color);
-        self.tertiary_color = tertiary_color;
-        self.tertiary_color_dark = tertiary_color_dark;
-        self.tertiary_color_light = tertiary_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_tertiary_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::TertiaryColor, color)
     }
 
     /// Sets the success color and its variants
-    pub fn set_success_color(mut self, color: &str) -> Self {
-        let (success_color, success_color_dark, success_color_light) = create_color_variants(color);
-        self.success_color = success_color;
-        self.success_color_dark = success_color_dark;
-        self.success_color_light = success_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_success_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::SuccessColor, color)
     }
 
     /// Sets the warning color and its variants
-    pub fn set_warning_color(mut self, color: &str) -> Self {
-        let (warning_color, warning_color_dark, warning_color_light) = create_color_variants(color);
-        self.warning_color = warning_color;
-        self.warning_color_dark = warning_color_dark;
-        self.warning_color_light = warning_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_warning_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::WarningColor, color)
     }
 
     /// Sets the error color and its variants
-    pub fn set_error_color(mut self, color: &str) -> Self {
-        let (error_color, error_color_dark, error_color_light) = create_color_variants(color);
-        self.error_color = error_color;
-        self.error_color_dark = error_color_dark;
-        self.error_color_light = error_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_error_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::ErrorColor, color)
     }
 
     /// Sets the disabled color and its variants
-    pub fn set_disabled_color(mut self, color: &str) -> Self {
-        let (disabled_color, disabled_color_dark, disabled_color_light) = create_color_variants(color);
-        self.disabled_color = disabled_color;
-        self.disabled_color_dark = disabled_color_dark;
-        self.disabled_color_light = disabled_color_light;
-        self
+    ///
+    /// An invalid color is logged and ignored. Use `Theme::builder` to handle invalid colors.
+    pub fn set_disabled_color(self, color: &str) -> Self {
+        self.set_color(ThemeField::DisabledColor, color)
     }
 }
 
 /// Properties for the ThemeProvider component
 #[derive(Properties, Clone, PartialEq)]
 pub struct ThemeProps {
-    /// The theme to be applied
-    pub theme: Theme,
+    /// The theme to be applied, used as the light theme when `dark_theme` is set. Without it, the
+    /// theme of the enclosing provider is inherited, or the default theme outside of any provider
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+    /// The theme applied in dark mode. Without it, `theme` is applied in every mode
+    #[prop_or_default]
+    pub dark_theme: Option<Theme>,
+    /// Colors overriding individual fields of the applied theme. Invalid colors are logged and
+    /// ignored
+    #[prop_or_default]
+    pub overrides: Vec<(ThemeField, String)>,
+    /// The mode used until the user picks one. Default is `ThemeMode::System`. Only applies to
+    /// the outermost provider, nested providers share its mode
+    #[prop_or_default]
+    pub default_mode: ThemeMode,
+    /// The `localStorage` key the mode picked by the user is persisted under. Only applies to the
+    /// outermost provider
+    #[prop_or(String::from(DEFAULT_STORAGE_KEY))]
+    pub storage_key: String,
     /// Child components that will inherit the theme
     pub children: Children,
 }
@@ -225,96 +687,136 @@ pub struct ThemeProps {
 ///
 /// This component applies the provided theme to its children by injecting CSS variables.
 ///
+/// The children are wrapped in an element with a generated scope class, and the variables are
+/// declared for that class only, so every provider themes its own subtree and providers can be
+/// nested. The outermost provider also declares the variables on `:root` for page-level styles
+/// such as the background and text colors of the `body`. The applied theme is available to
+/// descendants through the `use_theme` hook, and a nested provider without a `theme` inherits it,
+/// applying only its `overrides`.
+///
+/// With a `dark_theme`, the provider follows the `prefers-color-scheme` media query and switches
+/// themes as soon as the system setting changes. Descendants can read the mode and override it
+/// with the `use_theme` hook; the override is persisted in `localStorage` under `storage_key`,
+/// and setting `ThemeMode::System` removes it again. Nested providers share the mode of the
+/// outermost provider and ignore their own `default_mode` and `storage_key`.
+///
+/// # Properties
+///
+/// - `theme`: The theme to be applied. Default is the theme of the enclosing provider.
+/// - `dark_theme`: The theme applied in dark mode.
+/// - `overrides`: Colors overriding individual fields of the applied theme.
+/// - `default_mode`: The mode used until the user picks one. Default is `ThemeMode::System`.
+///   Outermost provider only.
+/// - `storage_key`: The `localStorage` key the picked mode is persisted under. Outermost provider
+///   only.
+/// - `children`: Child components that will inherit the theme.
+///
 /// # Example
 ///
 /// ```
-/// use your_crate::{
+/// use yew::prelude::*;
+/// use zirv_ui::{
 ///     Theme,
+///     ThemeField,
 ///     ThemeProvider,
 /// };
 ///
-/// let custom_theme =
-///     Theme::default().set_background_color("rgb(0,0,0)").set_primary_color("rgb(255,0,0)");
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let custom_theme =
+///         Theme::default().set_background_color("rgb(0,0,0)").set_primary_color("rgb(255,0,0)");
+///     let light_theme = Theme::default()
+///         .set_background_color("rgb(245,247,250)")
+///         .set_module_color("rgb(255,255,255)")
+///         .set_text_color_primary("rgb(33,37,41)");
 ///
-/// html! {
-///     <ThemeProvider theme={custom_theme}>
-///         <YourApp />
-///     </ThemeProvider>
+///     html! {
+///         <ThemeProvider theme={light_theme} dark_theme={custom_theme}>
+///             <main>{ "Content" }</main>
+///             <ThemeProvider overrides={vec![(ThemeField::PrimaryColor, "rgb(142,68,173)".to_string())]}>
+///                 <aside>{ "Sidebar" }</aside>
+///             </ThemeProvider>
+///         </ThemeProvider>
+///     }
 /// }
 /// ```
 #[function_component(ThemeProvider)]
 pub fn theme_provider(props: &ThemeProps) -> Html {
-    let theme = &props.theme;
+    let parent = use_context::<ThemeState>();
+    let scope = use_state(|| format!("theme-scope-{}", Uuid::new_v4().simple()));
 
-    let style = format!(
-        r#"
-        :root {{
-            --background-color: {};
-            --background-color-dark: {};
-            --background-color-light: {};
-            --module-color: {};
-            --module-color-dark: {};
-            --module-color-light: {};
-            --text-color-primary: {};
-            --text-color-secondary: {};
-            --color-primary: {};
-            --color-primary-dark: {};
-            --color-primary-light: {};
-            --color-secondary: {};
-            --color-secondary-dark: {};
-            --color-secondary-light: {};
-            --color-tertiery: {};
-            --color-tertiery-dark: {};
-            --color-tertiery-light: {};
-            --color-success: {};
-            --color-success-dark: {};
-            --color-success-light: {};
-            --color-warning: {};
-            --color-warning-dark: {};
-            --color-warning-light: {};
-            --color-error: {};
-            --color-error-dark: {};
-            --color-error-light: {};
-            --color-disabled: {};
-            --color-disabled-dark: {};
-            --color-disabled-light: {};
-        }}
-        "#,
-        theme.background_color,
-        theme.background_color_dark,
-        theme.background_color_light,
-        theme.module_color,
-        theme.module_color_dark,
-        theme.module_color_light,
-        theme.text_color_primary,
-        theme.text_color_secondary,
-        theme.primary_color,
-        theme.primary_color_dark,
-        theme.primary_color_light,
-        theme.secondary_color,
-        theme.secondary_color_dark,
-        theme.secondary_color_light,
-        theme.tertiary_color,
-        theme.tertiary_color_dark,
-        theme.tertiary_color_light,
-        theme.success_color,
-        theme.success_color_dark,
-        theme.success_color_light,
-        theme.warning_color,
-        theme.warning_color_dark,
-        theme.warning_color_light,
-        theme.error_color,
-        theme.error_color_dark,
-        theme.error_color_light,
-        theme.disabled_color,
-        theme.disabled_color_dark,
-        theme.disabled_color_light,
+    // Nested providers follow the mode of the outermost provider, so only the outermost one
+    // reads the stored mode and listens to the system setting
+    let nested = parent.is_some();
+    let storage_key = props.storage_key.clone();
+    let default_mode = props.default_mode;
+    let mode =
+        use_state_eq(move || if nested { default_mode } else { load_mode(&storage_key).unwrap_or(default_mode) });
+    let system_dark = use_state_eq(|| !nested && dark_scheme_query().is_some_and(|query| query.matches()));
+
+    {
+        // Follow changes of the system setting while the provider is mounted
+        let system_dark = system_dark.clone();
+        use_effect_with(nested, move |nested| {
+            let listener = (!*nested).then(dark_scheme_query).flatten().map(|query| {
+                EventListener::new(&query, "change", move |event| {
+                    if let Some(query) =
+                        event.current_target().and_then(|target| target.dyn_into::<MediaQueryList>().ok())
+                    {
+                        system_dark.set(query.matches());
+                    }
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    // Keep the callback stable so the context only changes with the mode or the theme
+    let set_mode =
+        use_callback((mode.setter(), props.storage_key.clone()), |new_mode: ThemeMode, (mode, storage_key)| {
+            store_mode(storage_key, new_mode);
+            mode.set(new_mode);
+        });
+
+    let (mode, is_dark, set_mode) = match &parent {
+        Some(parent) => (parent.mode, parent.is_dark, parent.set_mode.clone()),
+        None => (*mode, mode.is_dark(*system_dark), set_mode),
+    };
+
+    // Resolving the overrides and the shades is costly, so only redo it when an input changes
+    let resolved = use_memo(
+        (
+            props.theme.clone(),
+            props.dark_theme.clone(),
+            props.overrides.clone(),
+            is_dark,
+            parent.as_ref().map(|parent| parent.theme.clone()),
+        ),
+        |(theme, dark_theme, overrides, is_dark, parent_theme)| {
+            let theme = match (dark_theme, theme, parent_theme) {
+                (Some(dark_theme), _, _) if *is_dark => dark_theme.clone(),
+                (_, Some(theme), _) => theme.clone(),
+                (_, None, Some(parent_theme)) => parent_theme.clone(),
+                (_, None, None) => Theme::default(),
+            };
+            let theme = overrides.iter().fold(theme, |theme, (field, color)| theme.set_color(*field, color));
+            let variables = theme.css_variables();
+            (theme, variables)
+        },
     );
+    let (theme, variables) = &*resolved;
+
+    // Only the outermost provider declares the variables for the whole page
+    let selector = if nested { format!(".{}", *scope) } else { format!(":root, .{}", *scope) };
+    let style = format!("{} {{ {} }}", selector, variables);
+    let theme_state = ThemeState { mode, is_dark, set_mode, theme: theme.clone() };
 
     html! {
-    <>
+    <ContextProvider<ThemeState> context={theme_state}>
         <style>{ style }</style>
-        { props.children.clone() }
-    </>
+        <div class={classes!("theme-scope", (*scope).clone())}>
+            { props.children.clone() }
+        </div>
+    </ContextProvider<ThemeState>>
     }
 }
@@ -0,0 +1,106 @@
+//! Theme Mode Module
+//!
+//! This module provides the `ThemeMode` chosen by the user and the browser glue behind it: reading
+//! the system's `prefers-color-scheme` and persisting the user's choice in `localStorage`.
+
+use gloo::storage::{
+    LocalStorage,
+    Storage,
+};
+use web_sys::MediaQueryList;
+
+/// The media query matching a system preference for dark colors.
+pub const DARK_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Whether the light or the dark theme is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    /// Follows the `prefers-color-scheme` setting of the operating system.
+    #[default]
+    System,
+    /// Always shows the light theme.
+    Light,
+    /// Always shows the dark theme.
+    Dark,
+}
+
+impl ThemeMode {
+    /// Returns the name of the mode, as stored in `localStorage`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::System => "system",
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+        }
+    }
+
+    /// Parses the name of a mode.
+    ///
+    /// # Parameters
+    /// - `value`: The name of the mode, ignoring case and surrounding whitespace.
+    ///
+    /// # Returns
+    /// - The mode, or `None` if the name is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::theme::mode::ThemeMode;
+    ///
+    /// assert_eq!(ThemeMode::parse(" Dark "), Some(ThemeMode::Dark));
+    /// assert_eq!(ThemeMode::parse(ThemeMode::System.as_str()), Some(ThemeMode::System));
+    /// assert_eq!(ThemeMode::parse("sepia"), None);
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "system" => Some(ThemeMode::System),
+            "light" => Some(ThemeMode::Light),
+            "dark" => Some(ThemeMode::Dark),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the dark theme is shown in this mode.
+    ///
+    /// # Parameters
+    /// - `system_dark`: Whether the operating system prefers dark colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::theme::mode::ThemeMode;
+    ///
+    /// assert!(ThemeMode::System.is_dark(true));
+    /// assert!(!ThemeMode::Light.is_dark(true));
+    /// assert!(ThemeMode::Dark.is_dark(false));
+    /// ```
+    pub fn is_dark(&self, system_dark: bool) -> bool {
+        match self {
+            ThemeMode::System => system_dark,
+            ThemeMode::Light => false,
+            ThemeMode::Dark => true,
+        }
+    }
+}
+
+/// Returns the media query list for a system preference for dark colors, if the browser has one.
+pub fn dark_scheme_query() -> Option<MediaQueryList> {
+    gloo::utils::window().match_media(DARK_SCHEME_QUERY).ok().flatten()
+}
+
+/// Reads the mode persisted under `key`, if there is a valid one.
+pub fn load_mode(key: &str) -> Option<ThemeMode> {
+    LocalStorage::get::<String>(key).ok().and_then(|value| ThemeMode::parse(&value))
+}
+
+/// Persists the mode under `key`. `ThemeMode::System` removes the stored override instead.
+pub fn store_mode(key: &str, mode: ThemeMode) {
+    match mode {
+        ThemeMode::System => LocalStorage::delete(key),
+        mode => {
+            if let Err(error) = LocalStorage::set(key, mode.as_str()) {
+                log::warn!("Failed to persist the theme mode: {}", error);
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Theme Shades Module
+//!
+//! This module provides shade generation for theme colors in the OKLCH color space. OKLCH
+//! separates perceived lightness from chroma and hue, so changing only the lightness produces
+//! shades that look evenly spaced and keep the hue of the original color. Colors that fall outside
+//! of the sRGB gamut are brought back in by reducing their chroma rather than by clipping channels.
+
+use csscolorparser::Color;
+
+use crate::options::color::Shade;
+
+/// The OKLCH lightness of every shade, from `S50` to `S900`.
+const SHADE_LIGHTNESS: [f64; 10] = [0.97, 0.93, 0.87, 0.78, 0.69, 0.6, 0.51, 0.42, 0.34, 0.26];
+
+/// How much of the original chroma every shade keeps, from `S50` to `S900`. The lightest shades
+/// are close to white and get only a tint of the color.
+const SHADE_CHROMA: [f64; 10] = [0.15, 0.3, 0.5, 0.75, 0.9, 1.0, 1.0, 0.95, 0.85, 0.75];
+
+/// How far the dark and light variants of a color are moved in lightness.
+const VARIANT_LIGHTNESS_STEP: f64 = 0.07;
+
+/// A color in the OKLCH color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    /// The perceived lightness, from `0.0` (black) to `1.0` (white).
+    pub lightness: f64,
+    /// The chroma, `0.0` for grays.
+    pub chroma: f64,
+    /// The hue angle in degrees.
+    pub hue: f64,
+    /// The opacity, from `0.0` to `1.0`.
+    pub alpha: f64,
+}
+
+impl Oklch {
+    /// Converts an sRGB color to OKLCH.
+    pub fn from_color(color: &Color) -> Self {
+        let (lightness, a, b, alpha) = color.to_oklaba();
+        Self { lightness, chroma: a.hypot(b), hue: b.atan2(a).to_degrees().rem_euclid(360.0), alpha }
+    }
+
+    /// Converts the color to sRGB, reducing its chroma until it fits into the sRGB gamut.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use csscolorparser::Color;
+    /// use zirv_ui::theme::shades::Oklch;
+    ///
+    /// let red = Color::from_html("rgb(231,76,60)").unwrap();
+    /// assert_eq!(Oklch::from_color(&red).to_color().to_rgba8(), red.to_rgba8());
+    /// ```
+    pub fn to_color(&self) -> Color {
+        let lightness = self.lightness.clamp(0.0, 1.0);
+        let at_chroma = |chroma: f64| {
+            let (sin, cos) = self.hue.to_radians().sin_cos();
+            Color::from_oklaba(lightness, chroma * cos, chroma * sin, self.alpha)
+        };
+
+        let color = at_chroma(self.chroma);
+        if in_gamut(&color) {
+            return color;
+        }
+
+        // Binary search for the highest chroma that is still inside the gamut
+        let (mut low, mut high) = (0.0, self.chroma);
+        for _ in 0..24 {
+            let middle = (low + high) / 2.0;
+            if in_gamut(&at_chroma(middle)) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        at_chroma(low).clamp()
+    }
+
+    /// Returns the color with its lightness replaced.
+    pub fn with_lightness(self, lightness: f64) -> Self {
+        Self { lightness, ..self }
+    }
+}
+
+/// Generates the scale of shades of a color, from `S50` (lightest) to `S900` (darkest).
+///
+/// Every shade keeps the hue of the color and has a fixed lightness, so the shades of different
+/// colors line up: `S500` of every palette color is equally light.
+///
+/// # Parameters
+/// - `color`: The color to generate the shades of.
+///
+/// # Returns
+/// - Every shade with its color, from the lightest to the darkest.
+///
+/// # Example
+///
+/// ```rust
+/// use csscolorparser::Color;
+/// use zirv_ui::{
+///     options::color::Shade,
+///     theme::shades::{
+///         shade_scale,
+///         Oklch,
+///     },
+/// };
+///
+/// let scale = shade_scale(&Color::from_html("rgb(44,105,141)").unwrap());
+/// assert_eq!(scale.len(), 10);
+/// assert_eq!(scale[0].0, Shade::S50);
+///
+/// // The shades get darker at every step
+/// let lightness =
+///     scale.iter().map(|(_, color)| Oklch::from_color(color).lightness).collect::<Vec<_>>();
+/// assert!(lightness.windows(2).all(|pair| pair[0] > pair[1]));
+///
+/// // Even white gets a full scale
+/// let white = shade_scale(&Color::from_html("white").unwrap());
+/// assert_ne!(white[0].1.to_rgba8(), white[9].1.to_rgba8());
+/// ```
+pub fn shade_scale(color: &Color) -> Vec<(Shade, Color)> {
+    let base = Oklch::from_color(color);
+
+    Shade::ALL
+        .iter()
+        .map(|shade| {
+            let index = *shade as usize;
+            let oklch = Oklch { lightness: SHADE_LIGHTNESS[index], chroma: base.chroma * SHADE_CHROMA[index], ..base };
+            (*shade, oklch.to_color())
+        })
+        .collect()
+}
+
+/// Creates the dark and light variants of a color by moving its OKLCH lightness.
+///
+/// # Parameters
+/// - `color`: The color to create the variants of.
+///
+/// # Returns
+/// - The dark and the light variant.
+///
+/// # Example
+///
+/// ```rust
+/// use csscolorparser::Color;
+/// use zirv_ui::theme::shades::{
+///     variants,
+///     Oklch,
+/// };
+///
+/// let color = Color::from_html("rgb(37,46,66)").unwrap();
+/// let (dark, light) = variants(&color);
+/// let lightness = |color: &Color| Oklch::from_color(color).lightness;
+///
+/// assert!(lightness(&dark) < lightness(&color) - 0.05);
+/// assert!(lightness(&light) > lightness(&color) + 0.05);
+/// ```
+pub fn variants(color: &Color) -> (Color, Color) {
+    let base = Oklch::from_color(color);
+    let dark = base.with_lightness(base.lightness - VARIANT_LIGHTNESS_STEP);
+    let light = base.with_lightness(base.lightness + VARIANT_LIGHTNESS_STEP);
+    (dark.to_color(), light.to_color())
+}
+
+/// Returns whether a color lies inside the sRGB gamut, allowing for rounding errors.
+fn in_gamut(color: &Color) -> bool {
+    const EPSILON: f64 = 1e-4;
+    [color.r, color.g, color.b].iter().all(|channel| (-EPSILON..=1.0 + EPSILON).contains(channel))
+}
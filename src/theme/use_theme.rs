@@ -0,0 +1,69 @@
+//! Theme State Hook Module
+//!
+//! This module provides a custom hook for reading the theme and changing the mode of the nearest
+//! `ThemeProvider`. It leverages Yew's context system for state management.
+
+use yew::{
+    hook,
+    use_context,
+    Callback,
+};
+
+use super::{
+    mode::ThemeMode,
+    Theme,
+};
+
+/// Represents the state of a `ThemeProvider`
+#[derive(Clone, PartialEq)]
+pub struct ThemeState {
+    /// The mode chosen by the user, or `ThemeMode::System`
+    pub mode: ThemeMode,
+    /// Indicates whether the dark theme is currently shown
+    pub is_dark: bool,
+    /// Callback to change the mode, persisting it as the user's override
+    pub set_mode: Callback<ThemeMode>,
+    /// The theme applied by the provider, including its overrides
+    pub theme: Theme,
+}
+
+impl Default for ThemeState {
+    /// Provides a default state following the system with a no-op setter and the default theme
+    fn default() -> Self {
+        Self { mode: ThemeMode::System, is_dark: false, set_mode: Callback::noop(), theme: Theme::default() }
+    }
+}
+
+/// Custom hook to access the theme and mode of the nearest `ThemeProvider`
+///
+/// This hook attempts to retrieve the `ThemeState` from the current context.
+/// If no context is found, it returns the default state.
+///
+/// # Returns
+///
+/// Returns the current `ThemeState`, either from context or the default state.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use zirv_ui::theme::{
+///     mode::ThemeMode,
+///     use_theme::use_theme,
+/// };
+///
+/// #[function_component(ModeToggle)]
+/// fn mode_toggle() -> Html {
+///     let theme = use_theme();
+///     let next = if theme.is_dark { ThemeMode::Light } else { ThemeMode::Dark };
+///     let onclick = theme.set_mode.reform(move |_: MouseEvent| next);
+///
+///     html! {
+///         <button {onclick}>{ if theme.is_dark { "Light mode" } else { "Dark mode" } }</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_theme() -> ThemeState {
+    use_context::<ThemeState>().unwrap_or_default()
+}
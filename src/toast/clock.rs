@@ -0,0 +1,88 @@
+//! Toast Clock Module
+//!
+//! This module provides the monotonic clock used to measure how much time passes between two
+//! ticks of the `ToastsList`. The default `MonotonicClock` reads `performance.now()` in the
+//! browser, while the `ManualClock` only moves when told to, which makes toast lifetimes testable
+//! without a browser or real timers.
+
+use std::{
+    cell::Cell,
+    rc::Rc,
+};
+
+use time::Duration;
+
+/// Trait defining a monotonic clock.
+pub trait Clock {
+    /// Returns the time elapsed since an arbitrary but fixed origin.
+    ///
+    /// The returned value must never decrease between two calls.
+    fn now(&self) -> Duration;
+}
+
+/// The default clock, backed by `performance.now()` in the browser and by `std::time::Instant`
+/// on native targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MonotonicClock;
+
+impl Clock for MonotonicClock {
+    #[cfg(target_arch = "wasm32")]
+    fn now(&self) -> Duration {
+        let millis = web_sys::window().and_then(|window| window.performance()).map_or(0.0, |p| p.now());
+        Duration::seconds_f64(millis / 1000.0)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now(&self) -> Duration {
+        use std::{
+            sync::OnceLock,
+            time::Instant,
+        };
+
+        static ORIGIN: OnceLock<Instant> = OnceLock::new();
+        let elapsed = ORIGIN.get_or_init(Instant::now).elapsed();
+        Duration::try_from(elapsed).unwrap_or(Duration::MAX)
+    }
+}
+
+/// A clock that only advances when told to, for driving toasts deterministically in tests.
+///
+/// Clones share the same time, so a clone can be handed to a `ToastsList` while the original is
+/// used to advance it.
+///
+/// # Example
+///
+/// ```rust
+/// use time::Duration;
+/// use zirv_ui::toast::clock::{
+///     Clock,
+///     ManualClock,
+/// };
+///
+/// let clock = ManualClock::default();
+/// let handle = clock.clone();
+///
+/// handle.advance(Duration::milliseconds(250));
+/// assert_eq!(clock.now(), Duration::milliseconds(250));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    /// Moves the clock forward.
+    ///
+    /// # Parameters
+    /// - `duration`: The amount of time to advance the clock by. Negative durations are ignored to
+    ///   keep the clock monotonic.
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration.max(Duration::ZERO));
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This module provides a customizable `ToastComponent` for the Yew framework. The `ToastComponent`
 //! is used to display toast notifications with various properties and event handlers for click,
-//! mouse enter, and mouse leave events.
+//! mouse enter, and mouse leave events. Toasts that close by themselves show a progress bar
+//! counting down their remaining lifetime.
 
 use yew::{
     classes,
@@ -22,7 +23,7 @@ use crate::toast::utils::Notifiable;
 pub struct ToastComponentProps {
     /// The `Toast` data to display.
     pub toast: Toast,
-    /// Callback for click events.
+    /// Callback for click events. This is also invoked by actions that close the toast.
     pub onclick: Callback<MouseEvent>,
     /// Callback for mouse enter events.
     pub onenter: Callback<MouseEvent>,
@@ -45,25 +46,78 @@ pub struct ToastComponentProps {
 pub fn toast_component(props: &ToastComponentProps) -> Html {
     let title = &props.toast.title;
     let text = &props.toast.text;
+    let body = &props.toast.body;
     let toast_type = &props.toast.toast_type;
+    let icon = match &props.toast.icon {
+        Some(icon) => Some(icon.clone()),
+        None => toast_type.icon().map(Html::from),
+    };
 
     let onclick = &props.onclick;
     let onenter = &props.onenter;
     let onleave = &props.onleave;
 
+    let onclose = {
+        let onclick = onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            // The toast itself closes on click as well, so only close it once
+            event.stop_propagation();
+            onclick.emit(event);
+        })
+    };
+
     let mut classes = vec![classes!("toast"), toast_type.into()];
     if props.toast.is_paused() {
         classes.push(classes!("paused"));
     }
 
+    if props.toast.loading {
+        classes.push(classes!("toast-loading"));
+    }
+
     if let Some(additional_classes) = &props.toast.classes {
         classes.push(classes!(additional_classes));
     }
 
     html! {
         <div {onclick} onmouseenter={onenter} onmouseleave={onleave} class={classes}>
-            <span class={classes!("toast-title")}>{title}</span>
-            <span>{text}</span>
+            <div class={classes!("toast-header")}>
+                if let Some(icon) = icon {
+                    <span class={classes!("toast-icon")} aria-hidden="true">{icon}</span>
+                }
+                <span class={classes!("toast-title")}>{title}</span>
+                <button class={classes!("toast-close")} aria-label="Close notification" onclick={onclose}>{"×"}</button>
+            </div>
+            {match body {
+                Some(body) => body.clone(),
+                None => html! { <span>{text}</span> },
+            }}
+            if !props.toast.actions.is_empty() {
+                <div class={classes!("toast-actions")}>
+                    {for props.toast.actions.iter().map(|action| {
+                        let action = action.clone();
+                        let onclick = onclick.clone();
+                        let label = action.label.clone();
+
+                        let onaction = Callback::from(move |event: MouseEvent| {
+                            // Keep the click from reaching the toast, which would close it
+                            event.stop_propagation();
+                            action.callback.emit(());
+
+                            if action.close {
+                                onclick.emit(event);
+                            }
+                        });
+
+                        html! {
+                            <button class={classes!("toast-action")} onclick={onaction}>{label}</button>
+                        }
+                    })}
+                </div>
+            }
+            if let Some(remaining) = props.toast.remaining() {
+                <div class={classes!("toast-progress")} style={format!("width: {:.1}%;", remaining * 100.0)} />
+            }
         </div>
     }
 }
@@ -5,6 +5,7 @@
 //! and the `ToastsList` to handle the collection and state updates of toasts.
 
 use std::{
+    collections::VecDeque,
     fmt::Debug,
     rc::Rc,
 };
@@ -16,7 +17,16 @@ use yew::{
     UseReducerDispatcher,
 };
 
-use super::utils::Notifiable;
+use super::{
+    clock::{
+        Clock,
+        MonotonicClock,
+    },
+    utils::{
+        Notifiable,
+        ToastOrder,
+    },
+};
 
 /// The `ToastManager` is responsible for managing the creation and dispatching of toast
 /// notifications.
@@ -39,9 +49,62 @@ where
     ///
     /// # Parameters
     /// - `toast`: The toast notification to be added.
-    pub fn spawn(&self, toast: T) {
+    ///
+    /// # Returns
+    /// - The ID of the toast, which can be passed to `dismiss` and `update`.
+    pub fn spawn(&self, toast: T) -> Uuid {
+        let id = toast.id();
+        self.dispatch(Action::New(toast));
+        id
+    }
+
+    /// Closes a toast notification, whether it is visible or still queued.
+    ///
+    /// # Parameters
+    /// - `id`: The ID of the toast notification to close.
+    pub fn dismiss(&self, id: Uuid) {
+        self.dispatch(Action::Close(id));
+    }
+
+    /// Closes all toast notifications, including queued ones.
+    pub fn dismiss_all(&self) {
+        self.dispatch(Action::CloseAll);
+    }
+
+    /// Modifies a toast notification in place, e.g. to turn an "Uploading…" toast into an "Upload
+    /// finished" toast. Nothing happens if the toast has already been closed.
+    ///
+    /// # Parameters
+    /// - `id`: The ID of the toast notification to modify.
+    /// - `f`: The function applied to the toast notification.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::{
+    ///     Toast,
+    ///     ToastManager,
+    ///     ToastType,
+    /// };
+    ///
+    /// fn upload(toasts: &ToastManager<Toast>) {
+    ///     let id = toasts.spawn(Toast::new(ToastType::Info, "Upload", "Uploading…").persistent());
+    ///
+    ///     // Once the upload has finished
+    ///     toasts.update(id, |toast| {
+    ///         toast.set_text("Upload finished");
+    ///         toast.set_lifetime(Toast::LIFETIME);
+    ///     });
+    /// }
+    /// ```
+    pub fn update(&self, id: Uuid, f: impl FnOnce(&mut T) + 'static) {
+        self.dispatch(Action::Update(id, Box::new(f)));
+    }
+
+    /// Sends an action to the `ToastsList`, if the manager is connected to a provider.
+    pub(super) fn dispatch(&self, action: Action<T>) {
         if let Some(sender) = &self.sender {
-            sender.dispatch(Action::New(toast));
+            sender.dispatch(action);
         }
     }
 }
@@ -60,44 +123,270 @@ where
 /// # Parameters
 /// - `New(toast)`: Adds a new toast notification.
 /// - `Close(id)`: Closes a toast notification by its ID.
+/// - `CloseAll`: Closes all toast notifications, including queued ones.
+/// - `CloseNewest`: Closes the most recently shown toast notification.
+/// - `Configure { max_visible, order }`: Changes the stacking limit and order of the list.
+/// - `Update(id, f)`: Modifies a toast notification by its ID.
 /// - `Tick`: Updates the state of all toasts based on the elapsed time.
 /// - `Pause(id)`: Pauses a toast notification by its ID.
 /// - `Continue(id)`: Continues a toast notification by its ID.
-#[derive(Debug)]
+/// - `FocusIn(id)`: Pauses a toast notification by its ID when it receives keyboard focus.
+/// - `FocusOut(id)`: Continues a toast notification by its ID when it loses keyboard focus.
 pub enum Action<T>
 where
     T: Notifiable + PartialEq + Clone,
 {
     New(T),
     Close(Uuid),
+    CloseAll,
+    CloseNewest,
+    Configure { max_visible: Option<usize>, order: ToastOrder },
+    Update(Uuid, Box<dyn FnOnce(&mut T)>),
     Tick,
     Pause(Uuid),
     Continue(Uuid),
+    FocusIn(Uuid),
+    FocusOut(Uuid),
+}
+
+impl<T> Debug for Action<T>
+where
+    T: Notifiable + PartialEq + Clone + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::New(toast) => f.debug_tuple("New").field(toast).finish(),
+            Action::Close(id) => f.debug_tuple("Close").field(id).finish(),
+            Action::CloseAll => f.write_str("CloseAll"),
+            Action::CloseNewest => f.write_str("CloseNewest"),
+            Action::Configure { max_visible, order } => {
+                f.debug_struct("Configure").field("max_visible", max_visible).field("order", order).finish()
+            }
+            Action::Update(id, _) => f.debug_tuple("Update").field(id).finish_non_exhaustive(),
+            Action::Tick => f.write_str("Tick"),
+            Action::Pause(id) => f.debug_tuple("Pause").field(id).finish(),
+            Action::Continue(id) => f.debug_tuple("Continue").field(id).finish(),
+            Action::FocusIn(id) => f.debug_tuple("FocusIn").field(id).finish(),
+            Action::FocusOut(id) => f.debug_tuple("FocusOut").field(id).finish(),
+        }
+    }
 }
 
 /// A list of toast notifications.
 ///
+/// Toasts are stored in display order, from the top of the stack to the bottom. When
+/// `max_visible` is set, toasts beyond that limit wait in `queue` and are shown as soon as a
+/// visible toast is closed or expires.
+///
+/// The time that passes between two ticks is measured with a monotonic `Clock`, so toasts expire
+/// after their real lifetime even when the browser throttles the tick interval.
+///
 /// # Properties
-/// - `toasts`: A vector containing all the toast notifications.
-#[derive(Debug, Clone, PartialEq)]
+/// - `toasts`: A vector containing the visible toast notifications.
+/// - `queue`: The toast notifications waiting for a free slot, oldest first.
+#[derive(Clone)]
 pub struct ToastsList<T> {
     pub toasts: Vec<T>,
+    pub queue: VecDeque<T>,
+    pub(crate) max_visible: Option<usize>,
+    pub(crate) order: ToastOrder,
+    pub(crate) clock: Rc<dyn Clock>,
+    pub(crate) last_tick: Duration,
 }
 
 impl<T> Default for ToastsList<T> {
     fn default() -> Self {
-        Self { toasts: Default::default() }
+        let clock = MonotonicClock;
+
+        Self {
+            toasts: Default::default(),
+            queue: Default::default(),
+            max_visible: None,
+            order: ToastOrder::default(),
+            last_tick: clock.now(),
+            clock: Rc::new(clock),
+        }
+    }
+}
+
+impl<T: Debug> Debug for ToastsList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastsList")
+            .field("toasts", &self.toasts)
+            .field("queue", &self.queue)
+            .field("max_visible", &self.max_visible)
+            .field("order", &self.order)
+            .field("last_tick", &self.last_tick)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ToastsList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.toasts == other.toasts
+            && self.queue == other.queue
+            && self.max_visible == other.max_visible
+            && self.order == other.order
+            && Rc::ptr_eq(&self.clock, &other.clock)
+            && self.last_tick == other.last_tick
     }
 }
 
 impl<T> ToastsList<T> {
+    /// The interval between two ticks in milliseconds.
     pub const TIME_TICK_MILLIS: usize = 100;
+    /// The nominal interval between two ticks. The time actually applied to the toasts on a tick
+    /// is measured with the list's `Clock`.
     pub const TIME_TICK_DURATION: Duration = Duration::milliseconds(100);
 
+    /// Creates an empty list showing at most `max_visible` toasts, stacked in the given order.
+    ///
+    /// # Parameters
+    /// - `max_visible`: The maximum number of visible toasts, or `None` for no limit. A limit of
+    ///   `0` would never show a toast and is treated as no limit.
+    /// - `order`: Whether new toasts are added to the top or the bottom of the stack.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use time::Duration;
+    /// use uuid::Uuid;
+    /// use yew::Reducible;
+    /// use zirv_ui::toast::{
+    ///     manager::{
+    ///         Action,
+    ///         ToastsList,
+    ///     },
+    ///     utils::{
+    ///         Notifiable,
+    ///         ToastOrder,
+    ///     },
+    /// };
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Note(Uuid);
+    ///
+    /// impl Notifiable for Note {
+    ///     fn id(&self) -> Uuid {
+    ///         self.0
+    ///     }
+    ///
+    ///     fn apply_tick(&mut self, _time: Duration) {}
+    ///
+    ///     fn is_alive(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn is_paused(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     fn mouse_in(&mut self) {}
+    ///
+    ///     fn mouse_out(&mut self) {}
+    /// }
+    ///
+    /// let (first, second, third) = (Note(Uuid::new_v4()), Note(Uuid::new_v4()), Note(Uuid::new_v4()));
+    ///
+    /// let list = Rc::new(ToastsList::new(Some(2), ToastOrder::NewestOnTop));
+    /// let list = list.reduce(Action::New(first.clone()));
+    /// let list = list.reduce(Action::New(second.clone()));
+    /// let list = list.reduce(Action::New(third.clone()));
+    /// assert_eq!(list.toasts, vec![second.clone(), first.clone()]);
+    /// assert_eq!(list.queue, vec![third.clone()]);
+    ///
+    /// // Closing a visible toast makes room for the queued one
+    /// let list = list.reduce(Action::Close(first.id()));
+    /// assert_eq!(list.toasts, vec![third, second]);
+    /// assert!(list.queue.is_empty());
+    /// ```
+    pub fn new(max_visible: Option<usize>, order: ToastOrder) -> Self {
+        Self { max_visible: max_visible.filter(|max_visible| *max_visible > 0), order, ..Default::default() }
+    }
+
+    /// Replaces the clock measuring the time between ticks, e.g. with a `ManualClock` in tests.
+    ///
+    /// # Parameters
+    /// - `clock`: The monotonic clock to use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use time::Duration;
+    /// use yew::Reducible;
+    /// use zirv_ui::{
+    ///     toast::{
+    ///         clock::ManualClock,
+    ///         manager::{
+    ///             Action,
+    ///             ToastsList,
+    ///         },
+    ///     },
+    ///     Toast,
+    ///     ToastOrder,
+    ///     ToastType,
+    /// };
+    ///
+    /// let clock = ManualClock::default();
+    /// let list = Rc::new(ToastsList::new(None, ToastOrder::NewestOnTop).with_clock(clock.clone()));
+    ///
+    /// let toast = Toast::new(ToastType::Info, "Saved", "All changes saved")
+    ///     .with_lifetime(Duration::seconds(1));
+    /// let list = list.reduce(Action::New(toast));
+    ///
+    /// clock.advance(Duration::milliseconds(600));
+    /// let list = list.reduce(Action::Tick);
+    /// assert_eq!(list.toasts.len(), 1);
+    ///
+    /// clock.advance(Duration::milliseconds(400));
+    /// let list = list.reduce(Action::Tick);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.last_tick = clock.now();
+        self.clock = Rc::new(clock);
+        self
+    }
+
     /// Checks if the list of toasts is empty.
     pub fn is_empty(&self) -> bool {
         self.toasts.is_empty()
     }
+
+    /// Checks if another toast can be shown without exceeding `max_visible`.
+    fn has_free_slot(&self) -> bool {
+        self.max_visible.is_none_or(|max_visible| self.toasts.len() < max_visible)
+    }
+
+    /// Adds a toast to the visible stack according to the stacking order.
+    fn show(&mut self, toast: T) {
+        match self.order {
+            ToastOrder::NewestOnTop => self.toasts.insert(0, toast),
+            ToastOrder::NewestOnBottom => self.toasts.push(toast),
+        }
+    }
+
+    /// Returns the index of the most recently shown toast in the visible stack.
+    fn newest_index(&self) -> usize {
+        match self.order {
+            ToastOrder::NewestOnTop => 0,
+            ToastOrder::NewestOnBottom => self.toasts.len().saturating_sub(1),
+        }
+    }
+
+    /// Moves queued toasts to the visible stack while there is room for them.
+    fn promote_queued(&mut self) {
+        while self.has_free_slot() {
+            match self.queue.pop_front() {
+                Some(toast) => self.show(toast),
+                None => break,
+            }
+        }
+    }
 }
 
 impl<T> Reducible for ToastsList<T>
@@ -111,68 +400,289 @@ where
     /// # Parameters
     /// - `action`: The action to perform on the toast list.
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut list = (*self).clone();
+
         match action {
             Action::New(toast) => {
-                let mut toasts = self.toasts.clone();
-                toasts.push(toast);
+                // Ticks only run while toasts are shown, so restart the measurement when the
+                // first toast arrives
+                if list.toasts.is_empty() {
+                    list.last_tick = list.clock.now();
+                }
 
-                Rc::new(Self { toasts })
+                if list.has_free_slot() {
+                    list.show(toast);
+                } else {
+                    list.queue.push_back(toast);
+                }
             }
             Action::Close(id) => {
-                let toasts = self.toasts.clone().into_iter().filter(|t| t.id() != id).collect();
+                list.toasts.retain(|t| t.id() != id);
+                list.queue.retain(|t| t.id() != id);
+                list.promote_queued();
+            }
+            Action::CloseAll => {
+                list.toasts.clear();
+                list.queue.clear();
+            }
+            Action::CloseNewest => {
+                let newest = list.newest_index();
 
-                Rc::new(Self { toasts })
+                if newest < list.toasts.len() {
+                    list.toasts.remove(newest);
+                    list.promote_queued();
+                }
+            }
+            Action::Configure { max_visible, order } => {
+                if order != list.order {
+                    list.toasts.reverse();
+                    list.order = order;
+                }
+                list.max_visible = max_visible.filter(|max_visible| *max_visible > 0);
+
+                // Toasts beyond a lowered limit go back to the queue, newest first, so that they
+                // are shown again in the order they arrived
+                while list.max_visible.is_some_and(|max| list.toasts.len() > max) {
+                    let newest = list.newest_index();
+                    let toast = list.toasts.remove(newest);
+                    list.queue.push_front(toast);
+                }
+                list.promote_queued();
+            }
+            Action::Update(id, f) => {
+                if let Some(toast) = list.toasts.iter_mut().chain(list.queue.iter_mut()).find(|t| t.id() == id) {
+                    f(toast);
+                }
             }
             Action::Tick => {
-                let toasts = self
+                let now = list.clock.now();
+                let elapsed = now - list.last_tick;
+                list.last_tick = now;
+
+                list.toasts = list
                     .toasts
-                    .clone()
                     .into_iter()
                     .filter_map(|mut t| {
-                        if t.is_paused() {
-                            Some(t)
-                        } else if t.is_alive() {
-                            t.apply_tick(Self::TIME_TICK_DURATION);
-
-                            Some(t)
-                        } else {
-                            None
+                        if !t.is_paused() {
+                            t.apply_tick(elapsed);
                         }
+
+                        t.is_alive().then_some(t)
                     })
                     .collect();
-
-                Rc::new(Self { toasts })
+                list.promote_queued();
             }
             Action::Pause(id) => {
-                let toasts = self
-                    .toasts
-                    .clone()
-                    .into_iter()
-                    .map(|mut t| {
-                        if t.id() == id {
-                            t.mouse_in();
-                        }
-                        t
-                    })
-                    .collect();
-
-                Rc::new(Self { toasts })
+                for t in list.toasts.iter_mut().filter(|t| t.id() == id) {
+                    t.mouse_in();
+                }
             }
             Action::Continue(id) => {
-                let toasts = self
-                    .toasts
-                    .clone()
-                    .into_iter()
-                    .map(|mut t| {
-                        if t.id() == id {
-                            t.mouse_out();
-                        }
-                        t
-                    })
-                    .collect();
-
-                Rc::new(Self { toasts })
+                for t in list.toasts.iter_mut().filter(|t| t.id() == id) {
+                    t.mouse_out();
+                }
+            }
+            Action::FocusIn(id) => {
+                for t in list.toasts.iter_mut().filter(|t| t.id() == id) {
+                    t.focus_in();
+                }
+            }
+            Action::FocusOut(id) => {
+                for t in list.toasts.iter_mut().filter(|t| t.id() == id) {
+                    t.focus_out();
+                }
             }
         }
+
+        Rc::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+    use crate::toast::{
+        utils::ToastType,
+        Toast,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Note(Uuid);
+
+    impl Notifiable for Note {
+        fn id(&self) -> Uuid {
+            self.0
+        }
+
+        fn apply_tick(&mut self, _time: Duration) {}
+
+        fn is_alive(&self) -> bool {
+            true
+        }
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn mouse_in(&mut self) {}
+
+        fn mouse_out(&mut self) {}
+    }
+
+    fn notes(count: usize) -> Vec<Note> {
+        (0..count).map(|_| Note(Uuid::new_v4())).collect()
+    }
+
+    fn spawn(list: Rc<ToastsList<Note>>, notes: &[Note]) -> Rc<ToastsList<Note>> {
+        notes.iter().fold(list, |list, note| list.reduce(Action::New(note.clone())))
+    }
+
+    #[test]
+    fn default_order_adds_new_toasts_at_the_top() {
+        let notes = notes(3);
+        let list = spawn(Rc::new(ToastsList::default()), &notes);
+
+        assert_eq!(list.toasts, vec![notes[2].clone(), notes[1].clone(), notes[0].clone()]);
+        assert_eq!(list.order, ToastOrder::NewestOnTop);
+    }
+
+    #[test]
+    fn newest_on_bottom_adds_new_toasts_at_the_bottom() {
+        let notes = notes(3);
+        let list = spawn(Rc::new(ToastsList::new(None, ToastOrder::NewestOnBottom)), &notes);
+
+        assert_eq!(list.toasts, notes);
+    }
+
+    #[test]
+    fn toasts_beyond_the_limit_are_queued_and_promoted_in_order() {
+        let notes = notes(4);
+        let list = spawn(Rc::new(ToastsList::new(Some(2), ToastOrder::NewestOnBottom)), &notes);
+        assert_eq!(list.toasts, notes[..2]);
+        assert_eq!(list.queue, &notes[2..]);
+
+        let list = list.reduce(Action::Close(notes[0].id()));
+        assert_eq!(list.toasts, notes[1..3]);
+        assert_eq!(list.queue, &notes[3..]);
+
+        let list = list.reduce(Action::CloseNewest);
+        assert_eq!(list.toasts, vec![notes[1].clone(), notes[3].clone()]);
+        assert!(list.queue.is_empty());
+    }
+
+    #[test]
+    fn closing_a_queued_toast_removes_it_from_the_queue() {
+        let notes = notes(3);
+        let list = spawn(Rc::new(ToastsList::new(Some(1), ToastOrder::NewestOnBottom)), &notes);
+
+        let list = list.reduce(Action::Close(notes[1].id()));
+        assert_eq!(list.toasts, notes[..1]);
+        assert_eq!(list.queue, &notes[2..]);
+    }
+
+    #[test]
+    fn a_limit_of_zero_means_no_limit() {
+        let notes = notes(3);
+        let list = spawn(Rc::new(ToastsList::new(Some(0), ToastOrder::NewestOnBottom)), &notes);
+        assert_eq!(list.toasts, notes);
+
+        let list = list.reduce(Action::Configure { max_visible: Some(0), order: ToastOrder::NewestOnBottom });
+        assert_eq!(list.toasts, notes);
+        assert!(list.queue.is_empty());
+    }
+
+    #[test]
+    fn configure_reorders_and_applies_a_new_limit() {
+        let notes = notes(4);
+        let list = spawn(Rc::new(ToastsList::new(None, ToastOrder::NewestOnBottom)), &notes);
+
+        let list = list.reduce(Action::Configure { max_visible: None, order: ToastOrder::NewestOnTop });
+        assert_eq!(list.toasts, notes.iter().rev().cloned().collect::<Vec<_>>());
+
+        // Lowering the limit queues the newest toasts again, keeping their order of arrival
+        let list = list.reduce(Action::Configure { max_visible: Some(2), order: ToastOrder::NewestOnTop });
+        assert_eq!(list.toasts, vec![notes[1].clone(), notes[0].clone()]);
+        assert_eq!(list.queue, &notes[2..]);
+
+        // Raising it shows them again
+        let list = list.reduce(Action::Configure { max_visible: None, order: ToastOrder::NewestOnTop });
+        assert_eq!(list.toasts, notes.iter().rev().cloned().collect::<Vec<_>>());
+        assert!(list.queue.is_empty());
+    }
+
+    fn toasts(count: usize) -> Vec<Toast> {
+        (0..count).map(|index| Toast::new(ToastType::Info, format!("Toast {}", index), "Waiting")).collect()
+    }
+
+    #[test]
+    fn update_modifies_a_toast_in_place_by_id() {
+        let toasts = toasts(3);
+        let list = Rc::new(ToastsList::new(None, ToastOrder::NewestOnBottom));
+        let list = toasts.iter().fold(list, |list, toast| list.reduce(Action::New(toast.clone())));
+
+        let list = list.reduce(Action::Update(
+            toasts[1].id(),
+            Box::new(|toast: &mut Toast| {
+                toast.set_toast_type(ToastType::Success);
+                toast.set_text("Done");
+            }),
+        ));
+
+        let mut expected = toasts[1].clone();
+        expected.set_toast_type(ToastType::Success);
+        expected.set_text("Done");
+        assert_eq!(list.toasts, vec![toasts[0].clone(), expected, toasts[2].clone()]);
+    }
+
+    #[test]
+    fn update_modifies_a_queued_toast_and_ignores_closed_ones() {
+        let toasts = toasts(2);
+        let list = Rc::new(ToastsList::new(Some(1), ToastOrder::NewestOnBottom));
+        let list = toasts.iter().fold(list, |list, toast| list.reduce(Action::New(toast.clone())));
+
+        let list = list.reduce(Action::Update(toasts[1].id(), Box::new(|toast: &mut Toast| toast.set_text("Done"))));
+        assert_eq!(list.queue[0].text, "Done");
+
+        let list = list.reduce(Action::Close(toasts[0].id()));
+        let closed =
+            list.clone().reduce(Action::Update(toasts[0].id(), Box::new(|toast: &mut Toast| toast.set_text("Done"))));
+        assert_eq!(closed, list);
+    }
+
+    #[test]
+    fn dismiss_removes_a_visible_or_a_queued_toast() {
+        let notes = notes(4);
+        let list = spawn(Rc::new(ToastsList::new(Some(2), ToastOrder::NewestOnBottom)), &notes);
+
+        // Dismissing a queued toast leaves the visible ones alone
+        let list = list.reduce(Action::Close(notes[3].id()));
+        assert_eq!(list.toasts, notes[..2]);
+        assert_eq!(list.queue, &notes[2..3]);
+
+        // Dismissing a visible toast shows the next queued one
+        let list = list.reduce(Action::Close(notes[0].id()));
+        assert_eq!(list.toasts, notes[1..3]);
+        assert!(list.queue.is_empty());
+
+        // Dismissing an unknown toast changes nothing
+        let unchanged = list.clone().reduce(Action::Close(Uuid::new_v4()));
+        assert_eq!(unchanged, list);
+    }
+
+    #[test]
+    fn dismiss_all_removes_visible_and_queued_toasts() {
+        let notes = notes(3);
+        let list = spawn(Rc::new(ToastsList::new(Some(1), ToastOrder::NewestOnBottom)), &notes);
+
+        let list = list.reduce(Action::CloseAll);
+        assert!(list.is_empty());
+        assert!(list.queue.is_empty());
+
+        // The limit still applies to toasts spawned afterwards
+        let list = spawn(list, &notes);
+        assert_eq!(list.toasts, notes[..1]);
+        assert_eq!(list.queue, &notes[1..]);
     }
 }
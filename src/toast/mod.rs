@@ -5,25 +5,63 @@
 //! messages to the user and can be customized in terms of type, title, text, and appearance. The
 //! module also includes various submodules for
 
-use time::{
-    Duration,
-    OffsetDateTime,
-};
+use time::Duration;
 use uuid::Uuid;
+use yew::{
+    Callback,
+    Html,
+};
 
 use self::utils::{
     Notifiable,
+    Politeness,
     ToastType,
 };
 
+pub mod clock;
 pub mod component;
 pub mod component_factory;
 pub mod manager;
 pub mod message;
+pub mod promise;
 pub mod provider;
 pub mod use_toast;
 pub mod utils;
 
+/// A labelled button shown inside a toast, such as "Undo" or "Retry".
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToastAction {
+    pub(crate) label: String,
+    pub(crate) callback: Callback<()>,
+    pub(crate) close: bool,
+}
+
+impl ToastAction {
+    /// Creates a new `ToastAction` that leaves the toast open when triggered.
+    ///
+    /// # Parameters
+    ///
+    /// - `label`: The text of the action button.
+    /// - `callback`: The callback invoked when the action is triggered.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ToastAction` instance.
+    pub fn new(label: impl Into<String>, callback: Callback<()>) -> Self {
+        Self { label: label.into(), callback, close: false }
+    }
+
+    /// Makes the action close the toast after its callback has been invoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `ToastAction`.
+    pub fn closes_toast(mut self) -> Self {
+        self.close = true;
+        self
+    }
+}
+
 /// Represents a toast notification.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Toast {
@@ -31,16 +69,23 @@ pub struct Toast {
     pub(crate) toast_type: ToastType,
     pub(crate) title: String,
     pub(crate) text: String,
+    pub(crate) body: Option<Html>,
+    pub(crate) icon: Option<Html>,
+    pub(crate) actions: Vec<ToastAction>,
     pub(crate) classes: Option<String>,
-    pub(crate) spawn_time: OffsetDateTime,
+    pub(crate) loading: bool,
     pub(crate) lifetime: Duration,
     pub(crate) full_lifetime: Duration,
-    pub(crate) paused: bool,
+    pub(crate) persistent: bool,
+    pub(crate) hovered: bool,
+    pub(crate) focused: bool,
 }
 
 impl Toast {
     /// Default lifetime of a toast notification.
     pub const LIFETIME: Duration = Duration::milliseconds(3000);
+    /// Duration of the slide-in animation played when a toast appears.
+    pub const FADE_IN: Duration = Duration::milliseconds(500);
 
     /// Creates a new `Toast` notification.
     ///
@@ -59,13 +104,159 @@ impl Toast {
             toast_type,
             title: title.into(),
             text: text.into(),
-            classes: Some("toast-loading".to_owned()),
-            spawn_time: OffsetDateTime::now_local().expect("Unable to get current time"),
+            body: None,
+            icon: None,
+            actions: Vec::new(),
+            classes: None,
+            loading: true,
             lifetime: Self::LIFETIME,
             full_lifetime: Self::LIFETIME,
-            paused: false,
+            persistent: false,
+            hovered: false,
+            focused: false,
         }
     }
+
+    /// Sets how long the toast is shown before it closes itself.
+    ///
+    /// # Parameters
+    ///
+    /// - `lifetime`: The lifetime of the toast. Defaults to `Toast::LIFETIME`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Toast`.
+    pub fn with_lifetime(mut self, lifetime: Duration) -> Self {
+        self.lifetime = lifetime;
+        self.full_lifetime = lifetime;
+        self
+    }
+
+    /// Makes the toast persistent, so that it stays open until it is closed by the user.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Toast`.
+    pub fn persistent(mut self) -> Self {
+        self.persistent = true;
+        self
+    }
+
+    /// Adds CSS classes to the toast.
+    ///
+    /// # Parameters
+    ///
+    /// - `classes`: The additional classes, separated by spaces.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Toast`.
+    pub fn with_classes(mut self, classes: impl Into<String>) -> Self {
+        self.classes = Some(classes.into());
+        self
+    }
+
+    /// Replaces the text of the toast with custom HTML content.
+    ///
+    /// # Parameters
+    ///
+    /// - `body`: The content rendered below the title in place of the text.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Toast`.
+    pub fn with_body(mut self, body: Html) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Replaces the default icon of the toast's type, see `ToastType::icon`.
+    ///
+    /// # Parameters
+    ///
+    /// - `icon`: The icon shown next to the title. Pass an empty `html! {}` to hide the icon.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Toast`.
+    pub fn with_icon(mut self, icon: Html) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Adds an action button to the toast.
+    ///
+    /// # Parameters
+    ///
+    /// - `action`: The action to add. Actions are rendered in the order they are added.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Toast`.
+    pub fn with_action(mut self, action: ToastAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Changes the type of the toast.
+    ///
+    /// Together with the other setters this is meant for updating a toast that is already shown,
+    /// see `ToastManager::update`.
+    ///
+    /// # Parameters
+    ///
+    /// - `toast_type`: The new type of the toast.
+    pub fn set_toast_type(&mut self, toast_type: ToastType) {
+        self.toast_type = toast_type;
+    }
+
+    /// Changes the title of the toast.
+    ///
+    /// # Parameters
+    ///
+    /// - `title`: The new title of the toast.
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    /// Changes the text of the toast.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The new text of the toast.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+    }
+
+    /// Restarts the countdown of the toast with a new lifetime. A persistent toast stops being
+    /// persistent and closes once the lifetime has passed.
+    ///
+    /// # Parameters
+    ///
+    /// - `lifetime`: The new lifetime of the toast.
+    pub fn set_lifetime(&mut self, lifetime: Duration) {
+        self.lifetime = lifetime;
+        self.full_lifetime = lifetime;
+        self.persistent = false;
+    }
+
+    /// Returns the fraction of the lifetime that is left, from `1.0` when the toast appears down
+    /// to `0.0` when it closes.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` for persistent toasts, which never close by themselves.
+    pub fn remaining(&self) -> Option<f64> {
+        if self.persistent {
+            return None;
+        }
+
+        if self.full_lifetime <= Duration::ZERO {
+            return Some(0.0);
+        }
+
+        Some((self.lifetime / self.full_lifetime).clamp(0.0, 1.0))
+    }
 }
 
 impl Notifiable for Toast {
@@ -80,31 +271,54 @@ impl Notifiable for Toast {
     ///
     /// - `time`: The duration to decrease the lifetime by.
     fn apply_tick(&mut self, time: Duration) {
-        self.lifetime = self.lifetime.checked_sub(time).unwrap_or_default();
+        self.lifetime = (self.lifetime - time).max(Duration::ZERO);
 
-        if self.lifetime < Self::LIFETIME - Duration::milliseconds(500) {
-            self.classes = None;
+        if self.full_lifetime - self.lifetime >= Self::FADE_IN.min(self.full_lifetime) {
+            self.loading = false;
         }
     }
 
-    /// Checks if the toast is still alive (i.e., its lifetime is not zero).
+    /// Checks if the toast is still alive (i.e., it is persistent or its lifetime is not zero).
     ///
     /// # Returns
     ///
     /// Returns `true` if the toast is alive, `false` otherwise.
     fn is_alive(&self) -> bool {
-        self.lifetime != Duration::default()
+        self.persistent || self.lifetime != Duration::default()
     }
 
     /// Pauses the toast (e.g., when the mouse hovers over it).
     fn mouse_in(&mut self) {
-        self.paused = true;
+        self.hovered = true;
     }
 
-    /// Resumes the toast (e.g., when the mouse leaves it).
+    /// Resumes the toast when the mouse leaves it, unless it still has keyboard focus.
     fn mouse_out(&mut self) {
-        self.paused = false;
-        self.lifetime = self.full_lifetime;
+        self.hovered = false;
+        if !self.focused {
+            self.lifetime = self.full_lifetime;
+        }
+    }
+
+    /// Announces the title and text of the toast, interrupting the screen reader for warnings
+    /// and errors.
+    fn announcement(&self) -> Option<(String, Politeness)> {
+        let text = if self.text.is_empty() { self.title.clone() } else { format!("{}: {}", self.title, self.text) };
+
+        Some((text, self.toast_type.politeness()))
+    }
+
+    /// Pauses the toast when keyboard focus moves into it (e.g., onto its close button).
+    fn focus_in(&mut self) {
+        self.focused = true;
+    }
+
+    /// Resumes the toast when keyboard focus leaves it, unless the mouse is still over it.
+    fn focus_out(&mut self) {
+        self.focused = false;
+        if !self.hovered {
+            self.lifetime = self.full_lifetime;
+        }
     }
 
     /// Checks if the toast is paused.
@@ -113,6 +327,85 @@ impl Notifiable for Toast {
     ///
     /// Returns `true` if the toast is paused, `false` otherwise.
     fn is_paused(&self) -> bool {
-        self.paused
+        self.hovered || self.focused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use yew::Reducible;
+
+    use super::*;
+    use crate::toast::{
+        clock::ManualClock,
+        manager::{
+            Action,
+            ToastsList,
+        },
+        utils::ToastOrder,
+    };
+
+    fn list_with(clock: &ManualClock, toast: Toast) -> Rc<ToastsList<Toast>> {
+        Rc::new(ToastsList::new(None, ToastOrder::NewestOnTop).with_clock(clock.clone())).reduce(Action::New(toast))
+    }
+
+    fn tick(clock: &ManualClock, list: Rc<ToastsList<Toast>>, millis: i64) -> Rc<ToastsList<Toast>> {
+        clock.advance(Duration::milliseconds(millis));
+        list.reduce(Action::Tick)
+    }
+
+    #[test]
+    fn a_custom_lifetime_expires_at_its_own_lifetime() {
+        let clock = ManualClock::default();
+        let toast = Toast::new(ToastType::Info, "Saved", "").with_lifetime(Duration::seconds(5));
+        let list = list_with(&clock, toast);
+
+        let list = tick(&clock, list, 3000);
+        assert_eq!(list.toasts.len(), 1, "the toast outlives the default lifetime");
+
+        let list = tick(&clock, list, 1999);
+        assert_eq!(list.toasts.len(), 1);
+
+        let list = tick(&clock, list, 1);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn a_persistent_toast_never_expires() {
+        let clock = ManualClock::default();
+        let toast = Toast::new(ToastType::Info, "Uploading", "").persistent();
+        let mut list = list_with(&clock, toast);
+
+        for _ in 0..10 {
+            list = tick(&clock, list, 60_000);
+        }
+
+        assert_eq!(list.toasts.len(), 1);
+        assert_eq!(list.toasts[0].remaining(), None);
+    }
+
+    #[test]
+    fn remaining_falls_from_one_to_zero() {
+        let clock = ManualClock::default();
+        let toast = Toast::new(ToastType::Info, "Saved", "").with_lifetime(Duration::seconds(2));
+        let list = list_with(&clock, toast);
+        assert_eq!(list.toasts[0].remaining(), Some(1.0));
+
+        let list = tick(&clock, list, 500);
+        assert_eq!(list.toasts[0].remaining(), Some(0.75));
+
+        let list = tick(&clock, list, 1000);
+        assert_eq!(list.toasts[0].remaining(), Some(0.25));
+
+        // The reducer drops the toast once it has expired, so check the last step on the toast
+        let mut toast = list.toasts[0].clone();
+        toast.apply_tick(Duration::milliseconds(500));
+        assert_eq!(toast.remaining(), Some(0.0));
+        assert!(!toast.is_alive());
+
+        let list = tick(&clock, list, 500);
+        assert!(list.is_empty());
     }
 }
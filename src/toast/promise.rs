@@ -0,0 +1,236 @@
+//! Promise Toasts
+//!
+//! This module extends the `ToastManager` with promise-style toasts: a persistent loading toast is
+//! shown while a future runs and is swapped to a success or error toast once the future resolves.
+//! This replaces the spawn/update glue otherwise written around every request.
+
+use std::future::Future;
+
+use uuid::Uuid;
+use wasm_bindgen_futures::spawn_local;
+
+use super::{
+    manager::{
+        Action,
+        ToastManager,
+    },
+    utils::ToastType,
+    Toast,
+};
+
+impl ToastManager<Toast> {
+    /// Shows a toast that follows the progress of a future.
+    ///
+    /// A persistent toast with the `loading` text is shown immediately. When the future resolves,
+    /// the toast is updated in place: an `Ok` value turns it into a `ToastType::Info` toast with
+    /// the text built by `success`, an `Err` into a `ToastType::Error` toast with the text
+    /// built by `error`. Either way it then closes after `Toast::LIFETIME`.
+    ///
+    /// The future is driven by `wasm_bindgen_futures::spawn_local`, so it runs even if the manager
+    /// is not connected to a `ToastProvider`.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the toast.
+    /// - `future`: The future to follow.
+    /// - `loading`: The text shown while the future is running.
+    /// - `success`: Builds the text shown when the future resolves to `Ok`.
+    /// - `error`: Builds the text shown when the future resolves to `Err`.
+    ///
+    /// # Returns
+    /// - The ID of the toast.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::{
+    ///     Toast,
+    ///     ToastManager,
+    /// };
+    ///
+    /// async fn save() -> Result<usize, String> {
+    ///     Ok(3)
+    /// }
+    ///
+    /// fn on_save(toasts: &ToastManager<Toast>) {
+    ///     toasts.promise(
+    ///         "Save",
+    ///         save(),
+    ///         "Saving…",
+    ///         |count| format!("Saved {} items", count),
+    ///         |error| format!("Saving failed: {}", error),
+    ///     );
+    /// }
+    /// ```
+    pub fn promise<T, E, F>(
+        &self,
+        title: impl Into<String>,
+        future: F,
+        loading: impl Into<String>,
+        success: impl FnOnce(&T) -> String + 'static,
+        error: impl FnOnce(&E) -> String + 'static,
+    ) -> Uuid
+    where
+        F: Future<Output = Result<T, E>> + 'static,
+    {
+        let id = self.spawn(Toast::pending(title, loading));
+
+        let manager = self.clone();
+        spawn_local(async move {
+            let outcome = match future.await {
+                Ok(value) => Ok(success(&value)),
+                Err(err) => Err(error(&err)),
+            };
+
+            manager.dispatch(Action::settle(id, outcome));
+        });
+
+        id
+    }
+}
+
+impl Toast {
+    /// Creates the persistent toast shown by `ToastManager::promise` while its future is running.
+    ///
+    /// # Parameters
+    ///
+    /// - `title`: The title of the toast.
+    /// - `loading`: The text shown while the future is running.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new persistent `ToastType::Info` toast.
+    pub fn pending(title: impl Into<String>, loading: impl Into<String>) -> Self {
+        Self::new(ToastType::Info, title, loading).persistent()
+    }
+}
+
+impl Action<Toast> {
+    /// Builds the action that swaps a pending promise toast to the outcome of its future.
+    ///
+    /// An `Ok` text turns the toast into a `ToastType::Info` toast, an `Err` text into a
+    /// `ToastType::Error` toast. Either way the toast stops being persistent and closes after
+    /// `Toast::LIFETIME`.
+    ///
+    /// # Parameters
+    /// - `id`: The ID of the pending toast.
+    /// - `outcome`: The text to show, as the success or error of the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::Reducible;
+    /// use zirv_ui::{
+    ///     toast::{
+    ///         manager::{
+    ///             Action,
+    ///             ToastsList,
+    ///         },
+    ///         utils::Notifiable,
+    ///     },
+    ///     Toast,
+    /// };
+    ///
+    /// let toast = Toast::pending("Save", "Saving…");
+    /// let id = toast.id();
+    /// let list = Rc::new(ToastsList::default()).reduce(Action::New(toast));
+    ///
+    /// let list = list.reduce(Action::settle(id, Err("Saving failed".to_string())));
+    /// assert_eq!(list.toasts[0].remaining(), Some(1.0));
+    /// ```
+    pub fn settle(id: Uuid, outcome: Result<String, String>) -> Self {
+        Action::Update(
+            id,
+            Box::new(move |toast: &mut Toast| {
+                let (toast_type, text) = match outcome {
+                    Ok(text) => (ToastType::Info, text),
+                    Err(text) => (ToastType::Error, text),
+                };
+
+                toast.set_toast_type(toast_type);
+                toast.set_text(text);
+                toast.set_lifetime(Toast::LIFETIME);
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use time::Duration;
+    use yew::Reducible;
+
+    use super::*;
+    use crate::toast::{
+        clock::ManualClock,
+        manager::ToastsList,
+        utils::{
+            Notifiable,
+            ToastOrder,
+        },
+    };
+
+    fn pending(clock: &ManualClock) -> (Uuid, Rc<ToastsList<Toast>>) {
+        let toast = Toast::pending("Save", "Saving…");
+        let id = toast.id();
+        let list = Rc::new(ToastsList::new(None, ToastOrder::NewestOnTop).with_clock(clock.clone()));
+
+        (id, list.reduce(Action::New(toast)))
+    }
+
+    /// Lets a lot of time pass, as if the future took long to resolve.
+    fn wait(clock: &ManualClock, list: Rc<ToastsList<Toast>>) -> Rc<ToastsList<Toast>> {
+        clock.advance(Duration::minutes(5));
+        list.reduce(Action::Tick)
+    }
+
+    #[test]
+    fn the_loading_toast_stays_until_the_future_resolves() {
+        let clock = ManualClock::default();
+        let (_, list) = pending(&clock);
+        let list = wait(&clock, list);
+
+        assert_eq!(list.toasts.len(), 1);
+        assert_eq!(list.toasts[0].text, "Saving…");
+        assert_eq!(list.toasts[0].remaining(), None);
+    }
+
+    #[test]
+    fn a_success_turns_the_toast_into_an_info_toast_that_closes() {
+        let clock = ManualClock::default();
+        let (id, list) = pending(&clock);
+        let list = wait(&clock, list);
+
+        let list = list.reduce(Action::settle(id, Ok("Saved 3 items".to_string())));
+        let toast = &list.toasts[0];
+        assert_eq!(toast.id(), id);
+        assert_eq!(toast.toast_type, ToastType::Info);
+        assert_eq!(toast.text, "Saved 3 items");
+        assert_eq!(toast.remaining(), Some(1.0));
+
+        clock.advance(Toast::LIFETIME);
+        let list = list.reduce(Action::Tick);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn an_error_turns_the_toast_into_an_error_toast_that_closes() {
+        let clock = ManualClock::default();
+        let (id, list) = pending(&clock);
+        let list = wait(&clock, list);
+
+        let list = list.reduce(Action::settle(id, Err("Saving failed: offline".to_string())));
+        let toast = &list.toasts[0];
+        assert_eq!(toast.id(), id);
+        assert_eq!(toast.toast_type, ToastType::Error);
+        assert_eq!(toast.text, "Saving failed: offline");
+        assert_eq!(toast.remaining(), Some(1.0));
+
+        clock.advance(Toast::LIFETIME);
+        let list = list.reduce(Action::Tick);
+        assert!(list.is_empty());
+    }
+}
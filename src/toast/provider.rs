@@ -12,12 +12,15 @@ use yew::{
     function_component,
     html,
     use_effect_with,
+    use_node_ref,
     use_reducer_eq,
     Callback,
     Children,
     ContextProvider,
     Html,
+    KeyboardEvent,
     Properties,
+    UseReducerDispatcher,
 };
 
 use super::{
@@ -29,6 +32,9 @@ use super::{
     utils::{
         Notifiable,
         NotifiableComponentFactory,
+        Politeness,
+        ToastOrder,
+        ToastPosition,
     },
 };
 
@@ -39,6 +45,17 @@ pub struct ToastProviderProps<T: Notifiable + PartialEq, F: NotifiableComponentF
     pub children: Children,
     /// The component creator used to create toast components.
     pub component_creator: F,
+    /// The position of the toasts on the screen. Default is `ToastPosition::BottomRight`.
+    #[prop_or_default]
+    pub position: ToastPosition,
+    /// The maximum number of toasts shown at once. Further toasts are queued until a slot frees
+    /// up. Default is `None` (no limit), and `Some(0)` is treated as no limit as well.
+    #[prop_or_default]
+    pub max_visible: Option<usize>,
+    /// Whether new toasts are stacked on top or at the bottom. Default is
+    /// `ToastOrder::NewestOnTop`.
+    #[prop_or_default]
+    pub order: ToastOrder,
     /// A phantom data marker for the toast type.
     #[prop_or_default]
     pub _toast: PhantomData<T>,
@@ -50,16 +67,34 @@ pub struct ToastProviderProps<T: Notifiable + PartialEq, F: NotifiableComponentF
 /// within a specified context. It leverages the `ToastManager` and `ToastsList` to handle the
 /// lifecycle of toasts and the `NotifiableComponentFactory` to create the toast components.
 ///
+/// New toasts are announced to screen readers through two visually hidden live regions that stay
+/// in the page, a polite one and an assertive one for warnings and errors, see
+/// `Notifiable::announcement`. Pressing Escape while focus is inside the toasts, or on no element
+/// in particular, dismisses the most recent toast, unless another handler already handled the key.
+/// Toasts are paused while they have keyboard focus.
+///
 /// # Properties
 ///
 /// - `children`: The child components to render within the provider.
 /// - `component_creator`: The component creator used to create toast components.
+/// - `position`: The position of the toasts on the screen. Default is `ToastPosition::BottomRight`.
+/// - `max_visible`: The maximum number of toasts shown at once. Default is `None` (no limit).
+/// - `order`: Whether new toasts are stacked on top or at the bottom. Default is
+///   `ToastOrder::NewestOnTop`.
 /// - `_toast`: A phantom data marker for the toast type.
 #[function_component(ToastProvider)]
 pub fn toast_provider<T: Notifiable + PartialEq + Clone, F: NotifiableComponentFactory<T> + PartialEq + Clone>(
     props: &ToastProviderProps<T, F>,
 ) -> Html {
-    let toasts = use_reducer_eq(ToastsList::<T>::default);
+    let toasts = {
+        let (max_visible, order) = (props.max_visible, props.order);
+        use_reducer_eq(move || ToastsList::<T>::new(max_visible, order))
+    };
+
+    // Apply later changes of the stacking limit and order to the shown toasts
+    use_effect_with((props.max_visible, props.order, toasts.dispatcher()), |(max_visible, order, sender)| {
+        sender.dispatch(Action::Configure { max_visible: *max_visible, order: *order });
+    });
 
     let manager = ToastManager { sender: Some(toasts.dispatcher()) };
 
@@ -78,15 +113,73 @@ pub fn toast_provider<T: Notifiable + PartialEq + Clone, F: NotifiableComponentF
         move || drop(interval)
     });
 
+    // Escape dismisses the most recent toast while any toast is shown, but only when focus is in
+    // the toasts or on nothing in particular, so closing a dialog or a menu keeps the toasts
+    let region = use_node_ref();
+    use_effect_with((!toasts.is_empty(), toasts.dispatcher()), {
+        let region = region.clone();
+        move |(is_active, sender): &(bool, UseReducerDispatcher<ToastsList<T>>)| {
+            use gloo::events::EventListener;
+            use wasm_bindgen::JsCast;
+            use web_sys::Node;
+
+            let listener = is_active.then(|| {
+                let sender = sender.clone();
+                EventListener::new(&gloo::utils::document(), "keydown", move |event| {
+                    let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+                        return;
+                    };
+                    if event.key() != "Escape" || event.default_prevented() {
+                        return;
+                    }
+
+                    let target = event.target().and_then(|target| target.dyn_into::<Node>().ok());
+                    let in_toasts = region
+                        .cast::<Node>()
+                        .is_some_and(|region| target.as_ref().is_some_and(|target| region.contains(Some(target))));
+                    let document = gloo::utils::document();
+                    let on_nothing = target.as_ref().is_none_or(|target| {
+                        document.body().is_some_and(|body| body.is_same_node(Some(target)))
+                            || document.document_element().is_some_and(|root| root.is_same_node(Some(target)))
+                    });
+
+                    if in_toasts || on_nothing {
+                        sender.dispatch(Action::CloseNewest);
+                    }
+                })
+            });
+
+            move || drop(listener)
+        }
+    });
+
     let children = props.children.clone();
     let dispatcher = toasts.dispatcher();
 
     let toast_creator = &props.component_creator;
 
+    // The live regions are always rendered, as screen readers often ignore regions that are
+    // inserted together with their content. Keying on the text announces a toast again when it
+    // is updated in place.
+    let announcements = toasts
+        .toasts
+        .iter()
+        .filter_map(|toast| toast.announcement().map(|(text, politeness)| (toast.id(), text, politeness)))
+        .collect::<Vec<_>>();
+    let announcer = |politeness: Politeness| {
+        html! {
+            <div class={classes!("toasts-announcer")} aria-live={politeness.as_str()} aria-relevant="additions">
+                {for announcements.iter().filter(|(_, _, p)| *p == politeness).map(|(id, text, _)| html! {
+                    <p key={format!("{}-{}", id, text)}>{text}</p>
+                })}
+            </div>
+        }
+    };
+
     html! {
         <ContextProvider<ToastManager<T>> context={manager}>
             {children}
-            <div class={classes!("toasts", "toasts-provider-bottom-right")}>
+            <div ref={region} class={classes!("toasts", &props.position)} role="region" aria-label="Notifications">
                 {for toasts.toasts.iter().map(|t| {
                     let toast = t.clone();
                     let id = toast.id();
@@ -112,9 +205,31 @@ pub fn toast_provider<T: Notifiable + PartialEq + Clone, F: NotifiableComponentF
                         })
                     };
 
-                    toast_creator.component(toast, onclick, onenter, onleave)
+                    let onfocusin = {
+                        let dispatcher = dispatcher.clone();
+                        Callback::from(move |_| {
+                            dispatcher.dispatch(Action::FocusIn(id));
+                        })
+                    };
+
+                    let onfocusout = {
+                        let dispatcher = dispatcher.clone();
+                        Callback::from(move |_| {
+                            dispatcher.dispatch(Action::FocusOut(id));
+                        })
+                    };
+
+                    // Focus events bubble up from the toast's buttons, so the wrapper pauses the
+                    // toast for keyboard users just like hovering does for mouse users
+                    html! {
+                        <div key={id.to_string()} class={classes!("toasts-item")} {onfocusin} {onfocusout}>
+                            {toast_creator.component(toast, onclick, onenter, onleave)}
+                        </div>
+                    }
                 })}
             </div>
+            {announcer(Politeness::Polite)}
+            {announcer(Politeness::Assertive)}
         </ContextProvider<ToastManager<T>>>
     }
 }
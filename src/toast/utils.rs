@@ -49,6 +49,29 @@ pub trait Notifiable: Any {
 
     /// Resumes the toast notification (e.g., when the mouse leaves).
     fn mouse_out(&mut self);
+
+    /// Pauses the toast notification when keyboard focus moves into it.
+    ///
+    /// Defaults to `mouse_in`. Implementors that track hover and focus separately can override it
+    /// so that the toast stays paused while either is active.
+    fn focus_in(&mut self) {
+        self.mouse_in();
+    }
+
+    /// Resumes the toast notification when keyboard focus leaves it.
+    ///
+    /// Defaults to `mouse_out`.
+    fn focus_out(&mut self) {
+        self.mouse_out();
+    }
+
+    /// Returns the text announced to screen readers when the toast is shown, and how urgently.
+    ///
+    /// Defaults to `None`, in which case the toast component has to announce the toast itself,
+    /// e.g. with `role="status"`.
+    fn announcement(&self) -> Option<(String, Politeness)> {
+        None
+    }
 }
 
 /// Trait defining a factory for creating notifiable toast components.
@@ -75,22 +98,77 @@ pub trait NotifiableComponentFactory<T: Notifiable> {
     ) -> Html;
 }
 
+/// How urgently a toast is announced to screen readers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Politeness {
+    /// Announced once the screen reader is idle.
+    #[default]
+    Polite,
+
+    /// Announced immediately, interrupting the screen reader.
+    Assertive,
+}
+
+impl Politeness {
+    /// Returns the value of the `aria-live` attribute announcing with this politeness.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Politeness::Polite => "polite",
+            Politeness::Assertive => "assertive",
+        }
+    }
+}
+
 /// Enum representing the type of a toast notification.
 ///
 /// The `ToastType` enum categorizes toast notifications into different types,
-/// such as `Info`, `Warn`, and `Error`, which can be used to apply different
-/// styles to the toasts.
+/// such as `Info`, `Success`, `Warn`, and `Error`, which can be used to apply different
+/// styles to the toasts. `Custom` types map to a class of the user's choosing.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum ToastType {
     /// Informational toast notification.
     #[default]
     Info,
 
+    /// Success toast notification.
+    Success,
+
     /// Warning toast notification.
     Warn,
 
     /// Error toast notification.
     Error,
+
+    /// Custom toast notification, styled by the given CSS class.
+    Custom(String),
+}
+
+impl ToastType {
+    /// Returns how urgently toasts of this type are announced to screen readers.
+    ///
+    /// # Returns
+    /// - `Politeness::Assertive` for `Warn` and `Error` toasts, which interrupt the screen reader,
+    ///   and `Politeness::Polite` for all other types.
+    pub fn politeness(&self) -> Politeness {
+        match self {
+            ToastType::Warn | ToastType::Error => Politeness::Assertive,
+            ToastType::Info | ToastType::Success | ToastType::Custom(_) => Politeness::Polite,
+        }
+    }
+
+    /// Returns the default icon shown next to the title of toasts of this type.
+    ///
+    /// # Returns
+    /// - The icon glyph, or `None` for `Custom` types.
+    pub fn icon(&self) -> Option<&'static str> {
+        match self {
+            ToastType::Info => Some("ℹ"),
+            ToastType::Success => Some("✔"),
+            ToastType::Warn => Some("⚠"),
+            ToastType::Error => Some("✖"),
+            ToastType::Custom(_) => None,
+        }
+    }
 }
 
 impl From<&str> for ToastType {
@@ -100,13 +178,28 @@ impl From<&str> for ToastType {
     /// - `data`: The string slice to convert.
     ///
     /// # Returns
-    /// - The corresponding `ToastType`, or `Info` if the string does not match any type.
+    /// - The corresponding `ToastType`, ignoring case and surrounding whitespace. An empty string
+    ///   is `Info`, and any other string that does not match a built-in type is `Custom` with the
+    ///   string as class.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zirv_ui::ToastType;
+    ///
+    /// assert_eq!(ToastType::from("success"), ToastType::Success);
+    /// assert_eq!(ToastType::from("Success"), ToastType::Success);
+    /// assert_eq!(ToastType::from(" WARNING "), ToastType::Warn);
+    /// assert_eq!(ToastType::from(""), ToastType::Info);
+    /// assert_eq!(ToastType::from("promo"), ToastType::Custom("promo".to_string()));
+    /// ```
     fn from(data: &str) -> Self {
-        match data {
-            "info" => Self::Info,
-            "warn" => Self::Warn,
+        match data.trim().to_lowercase().as_str() {
+            "" | "info" => Self::Info,
+            "success" => Self::Success,
+            "warn" | "warning" => Self::Warn,
             "error" => Self::Error,
-            _ => Self::Info,
+            _ => Self::Custom(data.trim().to_string()),
         }
     }
 }
@@ -122,8 +215,58 @@ impl From<&ToastType> for Classes {
     fn from(toast_type: &ToastType) -> Self {
         match toast_type {
             ToastType::Info => classes!("info"),
+            ToastType::Success => classes!("success"),
             ToastType::Warn => classes!("warn"),
             ToastType::Error => classes!("error"),
+            ToastType::Custom(class) => classes!(class.clone()),
         }
     }
 }
+
+/// Enum representing the position of the toasts on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastPosition {
+    /// Top left corner of the screen.
+    TopLeft,
+    /// Centered along the top edge of the screen.
+    TopCenter,
+    /// Top right corner of the screen.
+    TopRight,
+    /// Bottom left corner of the screen.
+    BottomLeft,
+    /// Centered along the bottom edge of the screen.
+    BottomCenter,
+    /// Bottom right corner of the screen. This is the default.
+    #[default]
+    BottomRight,
+}
+
+impl From<&ToastPosition> for Classes {
+    /// Converts a `ToastPosition` into the `Classes` positioning the toasts container.
+    ///
+    /// # Parameters
+    /// - `position`: The `ToastPosition` to convert.
+    ///
+    /// # Returns
+    /// - The corresponding `Classes` for the `ToastPosition`.
+    fn from(position: &ToastPosition) -> Self {
+        match position {
+            ToastPosition::TopLeft => classes!("toasts-provider-top-left"),
+            ToastPosition::TopCenter => classes!("toasts-provider-top-center"),
+            ToastPosition::TopRight => classes!("toasts-provider-top-right"),
+            ToastPosition::BottomLeft => classes!("toasts-provider-bottom-left"),
+            ToastPosition::BottomCenter => classes!("toasts-provider-bottom-center"),
+            ToastPosition::BottomRight => classes!("toasts-provider-bottom-right"),
+        }
+    }
+}
+
+/// Enum representing the order in which toasts are stacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastOrder {
+    /// The newest toast is shown at the top of the stack. This is the default.
+    #[default]
+    NewestOnTop,
+    /// The newest toast is shown at the bottom of the stack.
+    NewestOnBottom,
+}
@@ -0,0 +1,95 @@
+//! Virtualize Module
+//!
+//! This module provides the windowing used to render long collections, such as the rows of a
+//! `Table` or the items of a `List`, without creating an element for every item. Only the items
+//! inside the scrolled viewport (plus a few overscan items on each side) are rendered, and two
+//! spacer elements take up the height of the items before and after them so the scrollbar keeps
+//! its size.
+//!
+//! The window itself is computed by the pure `visible_window` function, while the
+//! `use_virtual_window` hook observes the scroll container and measures the items in the browser.
+
+pub mod use_virtual_window;
+
+use std::ops::Range;
+
+/// The height of the items of a virtualized collection, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+    /// Every item has exactly this height.
+    Fixed(f64),
+    /// Items are about this high. The estimate is replaced by the average height of the rendered
+    /// items once they are measured.
+    Estimated(f64),
+}
+
+impl RowHeight {
+    /// Returns the height in pixels, at least one pixel.
+    pub fn px(&self) -> f64 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Estimated(height) => height.max(1.0),
+        }
+    }
+}
+
+/// The items to render from a virtualized collection.
+///
+/// # Properties
+/// - `start`: The index of the first rendered item.
+/// - `end`: The index after the last rendered item.
+/// - `before`: The height of the spacer in front of the rendered items, in pixels.
+/// - `after`: The height of the spacer behind the rendered items, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VisibleWindow {
+    pub start: usize,
+    pub end: usize,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl VisibleWindow {
+    /// Returns the range of rendered items.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// Computes the items to render for the current scroll position.
+///
+/// # Parameters
+/// - `total`: The number of items in the collection.
+/// - `row_height`: The height of one item in pixels.
+/// - `scroll_top`: How far the container is scrolled, in pixels.
+/// - `viewport_height`: The visible height of the container, in pixels.
+/// - `overscan`: The number of extra items rendered on each side of the viewport, which avoids
+///   blank areas while scrolling quickly.
+///
+/// # Returns
+/// - The `VisibleWindow` covering the viewport.
+///
+/// # Example
+///
+/// ```rust
+/// use zirv_ui::virtualize::visible_window;
+///
+/// let window = visible_window(50_000, 20.0, 2_000.0, 400.0, 5);
+/// assert_eq!(window.range(), 95..125);
+/// assert_eq!(window.before, 1_900.0);
+/// assert_eq!(window.after, (50_000.0 - 125.0) * 20.0);
+/// ```
+pub fn visible_window(
+    total: usize,
+    row_height: f64,
+    scroll_top: f64,
+    viewport_height: f64,
+    overscan: usize,
+) -> VisibleWindow {
+    let row_height = row_height.max(1.0);
+    let first = ((scroll_top.max(0.0) / row_height).floor() as usize).min(total);
+    let last = (((scroll_top.max(0.0) + viewport_height.max(0.0)) / row_height).ceil() as usize).min(total);
+
+    let start = first.saturating_sub(overscan);
+    let end = (last + overscan).min(total);
+
+    VisibleWindow { start, end, before: start as f64 * row_height, after: (total - end) as f64 * row_height }
+}
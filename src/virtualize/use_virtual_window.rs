@@ -0,0 +1,183 @@
+//! Virtual Window Hook Module
+//!
+//! This module provides the `use_virtual_window` hook, which connects the windowing of the
+//! `virtualize` module to the browser: it observes the scroll position and size of the scroll
+//! container and, for estimated item heights, measures the rendered items.
+
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use wasm_bindgen::{
+    closure::Closure,
+    JsCast,
+};
+use web_sys::{
+    Element,
+    HtmlElement,
+    ResizeObserver,
+};
+use yew::{
+    hook,
+    use_effect,
+    use_effect_with,
+    use_mut_ref,
+    use_state_eq,
+    NodeRef,
+};
+
+use super::{
+    visible_window,
+    RowHeight,
+    VisibleWindow,
+};
+
+/// The scroll position and height of a scroll container, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Viewport {
+    scroll_top: f64,
+    height: f64,
+}
+
+/// Custom hook computing the items of a long collection to render.
+///
+/// The hook listens to `scroll` events on the `container` element and observes its size with a
+/// `ResizeObserver`. Content above the items inside the container, such as the header of a table,
+/// is taken into account by measuring where the `items` element starts. To avoid re-rendering on
+/// every scrolled pixel, the component is only re-rendered when the scroll position crosses an item
+/// boundary. With `RowHeight::Estimated`, the element referenced by `items`, which holds the
+/// rendered items and the two spacers, is measured after every render to refine the estimate.
+///
+/// # Parameters
+/// - `container`: The scrolling element. It needs a bounded height and `overflow: auto`.
+/// - `items`: The element holding the rendered items and the spacers, e.g. a `<tbody>`.
+/// - `total`: The number of items in the collection.
+/// - `row_height`: The height of the items, or `None` to render every item.
+/// - `overscan`: The number of extra items rendered on each side of the viewport.
+///
+/// # Returns
+/// - The `VisibleWindow` to render. Without a `row_height` it covers all items.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::{
+///     function_component,
+///     html,
+///     use_node_ref,
+///     Html,
+/// };
+/// use zirv_ui::virtualize::{
+///     use_virtual_window::use_virtual_window,
+///     RowHeight,
+/// };
+///
+/// #[function_component(LongList)]
+/// fn long_list() -> Html {
+///     let items = (0..50_000).map(|index| format!("Item {}", index)).collect::<Vec<_>>();
+///     let container = use_node_ref();
+///     let list = use_node_ref();
+///     let window = use_virtual_window(container.clone(), list.clone(), items.len(), Some(RowHeight::Fixed(24.0)), 5);
+///
+///     html! {
+///         <div ref={container} style="height: 400px; overflow: auto;">
+///             <ul ref={list}>
+///                 <li style={format!("height: {}px", window.before)} />
+///                 {for items[window.range()].iter().map(|item| html! { <li style="height: 24px">{item}</li> })}
+///                 <li style={format!("height: {}px", window.after)} />
+///             </ul>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_virtual_window(
+    container: NodeRef,
+    items: NodeRef,
+    total: usize,
+    row_height: Option<RowHeight>,
+    overscan: usize,
+) -> VisibleWindow {
+    let viewport = use_state_eq(Viewport::default);
+    let measured = use_state_eq(|| row_height.map_or(1.0, |row_height| row_height.px()));
+
+    let height = match row_height {
+        Some(RowHeight::Fixed(height)) => height.max(1.0),
+        _ => *measured,
+    };
+    // The scroll listener outlives this render, so it reads the latest height through a cell
+    let current_height = use_mut_ref(|| height);
+    *current_height.borrow_mut() = height;
+
+    {
+        let viewport = viewport.clone();
+        let current_height = current_height.clone();
+
+        use_effect_with((container, items.clone(), row_height.is_some()), move |(container, items, enabled)| {
+            let listeners = container.cast::<Element>().filter(|_| *enabled).map(|element| {
+                let update = Rc::new({
+                    let element = element.clone();
+                    let items = items.clone();
+                    move || {
+                        let height = *current_height.borrow();
+                        let container_top = f64::from(element.scroll_top());
+                        // Where the items start within the scrolled content, below e.g. a header
+                        let items_top = items.cast::<Element>().map_or(0.0, |items| {
+                            items.get_bounding_client_rect().top() - element.get_bounding_client_rect().top()
+                                + container_top
+                        });
+                        let scroll_top = (container_top - items_top).max(0.0);
+                        viewport.set(Viewport {
+                            scroll_top: (scroll_top / height).floor() * height,
+                            height: f64::from(element.client_height()),
+                        });
+                    }
+                });
+                update();
+
+                let on_scroll = {
+                    let update = update.clone();
+                    EventListener::new(&element, "scroll", move |_| update())
+                };
+                let on_resize = Closure::<dyn FnMut()>::new(move || update());
+                let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref()).ok();
+                if let Some(observer) = observer.as_ref() {
+                    observer.observe(&element);
+                }
+                (on_scroll, observer, on_resize)
+            });
+
+            move || {
+                if let Some((_, Some(observer), _)) = listeners.as_ref() {
+                    observer.disconnect();
+                }
+                drop(listeners)
+            }
+        });
+    }
+
+    let window = match row_height {
+        Some(_) => visible_window(total, height, viewport.scroll_top, viewport.height, overscan),
+        None => VisibleWindow { start: 0, end: total, before: 0.0, after: 0.0 },
+    };
+
+    {
+        let measured = measured.clone();
+
+        use_effect(move || {
+            let rendered = window.end - window.start;
+
+            if let (Some(RowHeight::Estimated(_)), Some(element)) = (row_height, items.cast::<HtmlElement>()) {
+                if rendered > 0 {
+                    let average = (f64::from(element.offset_height()) - window.before - window.after) / rendered as f64;
+
+                    // Ignore sub-pixel differences so that rounding cannot cause a render loop
+                    if average >= 1.0 && (average - *measured).abs() > 0.5 {
+                        measured.set(average);
+                    }
+                }
+            }
+        });
+    }
+
+    window
+}